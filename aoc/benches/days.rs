@@ -0,0 +1,58 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use helpers::{input, Answer, Solution};
+
+// Benchmarks the parse, part1 and part2 phases of a day separately on the real puzzle input.
+// Each day is a criterion group named after the day, e.g. `day11/part2`.
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    // days without an input, e.g. before `aoc fetch`, are skipped
+    let input = match input::read(input::day_path(S::DAY)) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("skipping day {}, {}", S::DAY, e);
+            return;
+        }
+    };
+    let parsed = S::parse(&input).unwrap();
+
+    let mut group = c.benchmark_group(format!("day{}", S::DAY));
+    // some parts take seconds per iteration, criterion needs at least 10 samples
+    group.sample_size(10);
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    // there is nothing to measure for parts without a solution
+    if S::Part2::SOLVED {
+        group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    }
+    group.finish();
+}
+
+criterion_group!(
+    days,
+    bench_day::<day1::Day1>,
+    bench_day::<day2::Day2>,
+    bench_day::<day3::Day3>,
+    bench_day::<day4::Day4>,
+    bench_day::<day5::Day5>,
+    bench_day::<day6::Day6>,
+    bench_day::<day7::Day7>,
+    bench_day::<day8::Day8>,
+    bench_day::<day9::Day9>,
+    bench_day::<day10::Day10>,
+    bench_day::<day11::Day11>,
+    bench_day::<day12::Day12>,
+    bench_day::<day13::Day13>,
+    bench_day::<day14::Day14>,
+    bench_day::<day15::Day15>,
+    bench_day::<day16::Day16>,
+    bench_day::<day17::Day17>,
+    bench_day::<day18::Day18>,
+    bench_day::<day19::Day19>,
+    bench_day::<day20::Day20>,
+    bench_day::<day21::Day21>,
+    bench_day::<day22::Day22>,
+    bench_day::<day23::Day23>,
+    bench_day::<day24::Day24>,
+    bench_day::<day25::Day25>,
+);
+criterion_main!(days);
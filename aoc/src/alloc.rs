@@ -0,0 +1,129 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+// Global allocator keeping track of the currently allocated heap and its peak, so the runner can
+// report how much memory the phases of a solution need. Allocation itself is done by the system
+// allocator.
+pub struct CountingAllocator;
+
+static HEAP: Heap = Heap::new();
+
+// Bytes currently allocated and the most allocated at once
+struct Heap {
+    allocated: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+impl Heap {
+    const fn new() -> Self {
+        Self {
+            allocated: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+        }
+    }
+
+    fn add(&self, size: usize) {
+        let allocated = self.allocated.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak.fetch_max(allocated, Ordering::Relaxed);
+    }
+
+    fn sub(&self, size: usize) {
+        self.allocated.fetch_sub(size, Ordering::Relaxed);
+    }
+
+    fn measure<T>(&self, f: impl FnOnce() -> T) -> (T, Measurement) {
+        let before = self.allocated.load(Ordering::Relaxed);
+        self.peak.store(before, Ordering::Relaxed);
+
+        let start = Instant::now();
+        let result = f();
+        let duration = start.elapsed();
+
+        let peak_heap = self.peak.load(Ordering::Relaxed).saturating_sub(before);
+        (
+            result,
+            Measurement {
+                duration,
+                peak_heap,
+            },
+        )
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            HEAP.add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        HEAP.sub(layout.size());
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            HEAP.add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // count the new block before releasing the old one, the peak is an upper bound
+            HEAP.add(new_size);
+            HEAP.sub(layout.size());
+        }
+        new_ptr
+    }
+}
+
+// Wall time and peak heap usage of one phase of a solution
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Measurement {
+    pub duration: Duration,
+    // bytes allocated at the peak, on top of what was allocated before the phase started
+    pub peak_heap: usize,
+}
+
+// Runs f, measuring its wall time and peak heap usage. Measurements are not meaningful when other
+// threads allocate at the same time.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Measurement) {
+    HEAP.measure(f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure_peak_heap() {
+        // the global heap is shared with the other tests running in parallel, a heap of its own
+        // gives exact numbers
+        let heap = Heap::new();
+        heap.add(100);
+        let (sum, measurement) = heap.measure(|| {
+            heap.add(1 << 20);
+            heap.add(1 << 10);
+            heap.sub(1 << 20);
+            heap.add(1 << 19);
+            heap.sub(1 << 19);
+            3
+        });
+
+        assert_eq!(3, sum);
+        // the allocations before the phase don't count
+        assert_eq!((1 << 20) + (1 << 10), measurement.peak_heap);
+        assert_eq!(100 + (1 << 10), heap.allocated.load(Ordering::Relaxed));
+
+        // a phase which only frees has no peak of its own
+        let ((), measurement) = heap.measure(|| heap.sub(100));
+        assert_eq!(0, measurement.peak_heap);
+    }
+}
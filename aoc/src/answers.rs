@@ -0,0 +1,126 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+// Expected answers keyed by day and part, e.g.
+// [day1]
+// part1 = 1016964
+// part2 = 182588480
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, DayAnswers>);
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct DayAnswers {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+// Most answers are numbers, but some puzzles expect a text (e.g. the cup labels of day 23)
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(u64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl Answers {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn Error>> {
+        let content = helpers::input::read(path)?;
+        Ok(content.parse()?)
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        let answers = self.0.get(&format!("day{}", day))?;
+        match part {
+            1 => answers.part1.as_ref(),
+            2 => answers.part2.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Status {
+        match self.get(day, part) {
+            Some(expected) if expected.to_string() == answer => Status::Pass,
+            Some(_) => Status::Fail,
+            None => Status::Missing,
+        }
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = toml::de::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        toml::from_str(s)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let status = match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+        };
+        // pad, so the status can be used in table columns
+        f.pad(status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str =
+        "[day1]\npart1 = 514579\npart2 = 241861950\n\n[day23]\npart1 = \"67384529\"\n";
+
+    #[test]
+    fn test_get() {
+        let answers: Answers = ANSWERS.parse().unwrap();
+
+        assert_eq!(Some(&Answer::Number(514579)), answers.get(1, 1));
+        assert_eq!(Some(&Answer::Number(241861950)), answers.get(1, 2));
+        assert_eq!(
+            Some(&Answer::Text("67384529".to_string())),
+            answers.get(23, 1)
+        );
+        assert_eq!(None, answers.get(23, 2));
+        assert_eq!(None, answers.get(2, 1));
+    }
+
+    #[test]
+    fn test_check() {
+        let answers: Answers = ANSWERS.parse().unwrap();
+
+        assert_eq!(Status::Pass, answers.check(1, 1, "514579"));
+        assert_eq!(Status::Fail, answers.check(1, 2, "241861951"));
+        assert_eq!(Status::Pass, answers.check(23, 1, "67384529"));
+        assert_eq!(Status::Missing, answers.check(23, 2, "unsolved"));
+    }
+
+    #[test]
+    fn test_answers_file() {
+        let answers = Answers::from_file("../assets/answers.toml").unwrap();
+        assert_eq!(Some(&Answer::Number(1016964)), answers.get(1, 1));
+        assert_eq!(None, answers.get(25, 2));
+    }
+}
@@ -0,0 +1,213 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::ffi::OsString;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+// Criterion stores every benchmark run as
+// `<criterion dir>/<group>/<bench>/<baseline>/estimates.json`, with
+// `cargo bench -- --save-baseline <name>` choosing the baseline name.
+// Only the mean is compared, the html reports of criterion have the details.
+#[derive(Deserialize)]
+struct Estimates {
+    mean: Estimate,
+}
+
+#[derive(Deserialize)]
+struct Estimate {
+    point_estimate: f64,
+}
+
+// mean duration of each benchmark in nanoseconds, keyed by benchmark id, e.g. `day11/part2`
+type Means = BTreeMap<String, f64>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub benchmark: String,
+    pub old: Option<f64>,
+    pub new: Option<f64>,
+}
+
+impl Comparison {
+    // relative change in percent, negative values are improvements
+    pub fn change(&self) -> Option<f64> {
+        match (self.old, self.new) {
+            (Some(old), Some(new)) => Some((new - old) / old * 100.0),
+            _ => None,
+        }
+    }
+}
+
+// `criterion` in the target directory of cargo, `CARGO_TARGET_DIR` or `target` in the workspace
+pub fn default_dir() -> PathBuf {
+    default_dir_from(std::env::var_os("CARGO_TARGET_DIR"))
+}
+
+fn default_dir_from(target_dir: Option<OsString>) -> PathBuf {
+    let target_dir = match target_dir {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => Path::new(env!("CARGO_MANIFEST_DIR")).join("../target"),
+    };
+    target_dir.join("criterion")
+}
+
+pub fn compare(
+    criterion_dir: &Path,
+    old: &str,
+    new: &str,
+) -> Result<Vec<Comparison>, Box<dyn Error>> {
+    let old_means = read_baseline(criterion_dir, old)?;
+    let new_means = read_baseline(criterion_dir, new)?;
+
+    let mut benchmarks: Vec<_> = old_means.keys().chain(new_means.keys()).collect();
+    benchmarks.sort();
+    benchmarks.dedup();
+    Ok(benchmarks
+        .into_iter()
+        .map(|benchmark| Comparison {
+            benchmark: benchmark.clone(),
+            old: old_means.get(benchmark).copied(),
+            new: new_means.get(benchmark).copied(),
+        })
+        .collect())
+}
+
+fn read_baseline(criterion_dir: &Path, baseline: &str) -> Result<Means, Box<dyn Error>> {
+    let mut means = Means::new();
+    collect_means(criterion_dir, criterion_dir, baseline, &mut means)?;
+    if means.is_empty() {
+        return Err(CompareError::MissingBaseline(baseline.to_string()).into());
+    }
+    Ok(means)
+}
+
+fn collect_means(
+    root: &Path,
+    dir: &Path,
+    baseline: &str,
+    means: &mut Means,
+) -> Result<(), Box<dyn Error>> {
+    let estimates = dir.join(baseline).join("estimates.json");
+    if estimates.is_file() {
+        let content = helpers::read_file(&estimates)?;
+        let estimates: Estimates = serde_json::from_str(&content)?;
+        let id = dir.strip_prefix(root)?.to_string_lossy().replace('\\', "/");
+        means.insert(id, estimates.mean.point_estimate);
+        return Ok(());
+    }
+
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_means(root, &path, baseline, means)?;
+        }
+    }
+    Ok(())
+}
+
+// Renders the comparisons as markdown table
+pub struct Report<'a> {
+    pub old: &'a str,
+    pub new: &'a str,
+    pub comparisons: &'a [Comparison],
+}
+
+impl<'a> Display for Report<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "| benchmark | {} | {} | change |", self.old, self.new)?;
+        writeln!(f, "|---|---:|---:|---:|")?;
+        for c in self.comparisons {
+            let change = c
+                .change()
+                .map_or_else(|| "-".to_string(), |change| format!("{:+.2}%", change));
+            writeln!(
+                f,
+                "| {} | {} | {} | {} |",
+                c.benchmark,
+                format_duration(c.old),
+                format_duration(c.new),
+                change
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn format_duration(nanos: Option<f64>) -> String {
+    match nanos {
+        None => "-".to_string(),
+        Some(n) if n < 1e3 => format!("{:.2} ns", n),
+        Some(n) if n < 1e6 => format!("{:.2} µs", n / 1e3),
+        Some(n) if n < 1e9 => format!("{:.2} ms", n / 1e6),
+        Some(n) => format!("{:.2} s", n / 1e9),
+    }
+}
+
+#[derive(Clone, Debug, Error)]
+enum CompareError {
+    #[error("no benchmarks found for baseline {0}")]
+    MissingBaseline(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn write_estimate(root: &Path, benchmark: &str, baseline: &str, mean: f64) {
+        let dir = root.join(benchmark).join(baseline);
+        fs::create_dir_all(&dir).unwrap();
+        let json = format!(
+            r#"{{"mean":{{"confidence_interval":{{"confidence_level":0.95,"lower_bound":{0},"upper_bound":{0}}},"point_estimate":{0},"standard_error":0.0}}}}"#,
+            mean
+        );
+        fs::write(dir.join("estimates.json"), json).unwrap();
+    }
+
+    #[test]
+    fn test_compare() {
+        let root = std::env::temp_dir().join(format!("aoc-compare-{}", std::process::id()));
+        write_estimate(&root, "day11/part1", "before", 2e9);
+        write_estimate(&root, "day11/part1", "after", 5e8);
+        write_estimate(&root, "day15/part2", "before", 1500.0);
+        write_estimate(&root, "day25/part1", "after", 20.0);
+
+        let comparisons = compare(&root, "before", "after").unwrap();
+        assert!(compare(&root, "before", "unknown").is_err());
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(3, comparisons.len());
+        assert_eq!("day11/part1", comparisons[0].benchmark);
+        assert_eq!(Some(-75.0), comparisons[0].change());
+        assert_eq!(None, comparisons[1].change());
+
+        let report = Report {
+            old: "before",
+            new: "after",
+            comparisons: &comparisons,
+        }
+        .to_string();
+        let mut lines = report.lines().skip(2);
+        assert_eq!(
+            Some("| day11/part1 | 2.00 s | 500.00 ms | -75.00% |"),
+            lines.next()
+        );
+        assert_eq!(Some("| day15/part2 | 1.50 µs | - | - |"), lines.next());
+        assert_eq!(Some("| day25/part1 | - | 20.00 ns | - |"), lines.next());
+    }
+
+    #[test]
+    fn test_default_dir() {
+        // the workspace target directory, wherever the runner is started
+        let dir = default_dir_from(None);
+        assert!(dir.ends_with("target/criterion"));
+        assert!(dir.parent().unwrap().join("../Cargo.toml").is_file());
+        assert_eq!(dir, default_dir_from(Some(OsString::new())));
+        assert_eq!(
+            PathBuf::from("/elsewhere/criterion"),
+            default_dir_from(Some("/elsewhere".into()))
+        );
+    }
+}
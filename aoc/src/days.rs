@@ -0,0 +1,127 @@
+use crate::alloc::{measure, Measurement};
+use helpers::{Answer as _, Details, Solution};
+use std::error::Error;
+use std::io::BufRead;
+use tracing::info_span;
+
+// Parses the input once and solves the requested parts
+pub type Solver = fn(&str, &[u8]) -> Result<Solved, Box<dyn Error>>;
+
+// Solves part 1 while reading the input, only a few days can do without the whole input
+pub type StreamSolver = fn(&mut dyn BufRead) -> Result<Answer, Box<dyn Error>>;
+
+pub struct Day {
+    pub day: u8,
+    pub solve: Solver,
+    pub stream: Option<StreamSolver>,
+}
+
+pub struct Solved {
+    // a streamed input is parsed while solving, there is no parse phase of its own
+    pub parse: Option<Measurement>,
+    pub answers: Vec<Answer>,
+}
+
+pub struct Answer {
+    pub part: u8,
+    // the printable answer
+    pub answer: String,
+    pub details: Details,
+    pub measurement: Measurement,
+}
+
+// Every phase runs in its own span, so the traces of the days can be told apart
+fn solve<S: Solution>(input: &str, parts: &[u8]) -> Result<Solved, Box<dyn Error>> {
+    let (input, parse) = {
+        let span = info_span!("parse", day = S::DAY);
+        measure(|| span.in_scope(|| S::parse(input)))
+    };
+    let input = input?;
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let span = info_span!("part", day = S::DAY, part);
+            let (answer, measurement) = measure(|| {
+                span.in_scope(|| match part {
+                    1 => S::part1(&input).map(|answer| (answer.to_string(), answer.details())),
+                    _ => S::part2(&input).map(|answer| (answer.to_string(), answer.details())),
+                })
+            });
+            let (answer, details) = answer?;
+            Ok(Answer {
+                part,
+                answer,
+                details,
+                measurement,
+            })
+        })
+        .collect::<Result<_, Box<dyn Error>>>()?;
+
+    Ok(Solved {
+        parse: Some(parse),
+        answers,
+    })
+}
+
+// Only part 1 of day 9 gets by with the numbers of the preamble
+fn stream_day9(reader: &mut dyn BufRead) -> Result<Answer, Box<dyn Error>> {
+    let span = info_span!("part", day = 9, part = 1);
+    let (answer, measurement) = measure(|| span.in_scope(|| day9::part1_from_reader(reader)));
+    let answer = answer?;
+    Ok(Answer {
+        part: 1,
+        answer: answer.to_string(),
+        details: answer.details(),
+        measurement,
+    })
+}
+
+macro_rules! day {
+    ($solution:ty) => {
+        Day {
+            day: <$solution>::DAY,
+            solve: solve::<$solution>,
+            stream: None,
+        }
+    };
+    ($solution:ty, stream: $stream:expr) => {
+        Day {
+            day: <$solution>::DAY,
+            solve: solve::<$solution>,
+            stream: Some($stream),
+        }
+    };
+}
+
+pub static DAYS: &[Day] = &[
+    day!(day1::Day1),
+    day!(day2::Day2),
+    day!(day3::Day3),
+    day!(day4::Day4),
+    day!(day5::Day5),
+    day!(day6::Day6),
+    day!(day7::Day7),
+    day!(day8::Day8),
+    day!(day9::Day9, stream: stream_day9),
+    day!(day10::Day10),
+    day!(day11::Day11),
+    day!(day12::Day12),
+    day!(day13::Day13),
+    day!(day14::Day14),
+    day!(day15::Day15),
+    day!(day16::Day16),
+    day!(day17::Day17),
+    day!(day18::Day18),
+    day!(day19::Day19),
+    day!(day20::Day20),
+    day!(day21::Day21),
+    day!(day22::Day22),
+    day!(day23::Day23),
+    day!(day24::Day24),
+    day!(day25::Day25),
+];
+
+pub fn get(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
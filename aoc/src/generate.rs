@@ -0,0 +1,1072 @@
+use helpers::{matching, Grid};
+use rand::seq::SliceRandom;
+use rand::Rng;
+use rand_chacha::ChaCha8Rng;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::fmt::{Display, Write};
+
+// Random puzzle inputs in the format of the real inputs, e.g. to benchmark the solutions on larger
+// inputs. Every part of a generated input can be solved, like the part 2 patch of day 8 or the
+// unique field mapping of day 16. The same seed always generates the same input.
+pub type Random = ChaCha8Rng;
+
+pub struct Generator {
+    pub day: u8,
+    // about the size of the real input, what is counted differs per day
+    pub default_size: usize,
+    pub generate: fn(&mut Random, usize) -> String,
+}
+
+impl Generator {
+    const fn new(day: u8, default_size: usize, generate: fn(&mut Random, usize) -> String) -> Self {
+        Self {
+            day,
+            default_size,
+            generate,
+        }
+    }
+}
+
+pub static GENERATORS: &[Generator] = &[
+    Generator::new(1, 200, day1),
+    Generator::new(2, 1000, day2),
+    Generator::new(3, 323, day3),
+    Generator::new(4, 290, day4),
+    Generator::new(5, 800, day5),
+    Generator::new(6, 490, day6),
+    Generator::new(7, 594, day7),
+    Generator::new(8, 630, day8),
+    Generator::new(9, 1000, day9),
+    Generator::new(10, 100, day10),
+    Generator::new(11, 95, day11),
+    Generator::new(12, 780, day12),
+    Generator::new(13, 60, day13),
+    Generator::new(14, 570, day14),
+    Generator::new(15, 7, day15),
+    Generator::new(16, 240, day16),
+    Generator::new(17, 8, day17),
+    Generator::new(18, 370, day18),
+    Generator::new(19, 470, day19),
+    Generator::new(20, 12, day20),
+    Generator::new(21, 40, day21),
+    Generator::new(22, 25, day22),
+    Generator::new(23, 9, day23),
+    Generator::new(24, 350, day24),
+    Generator::new(25, 10_000_000, day25),
+];
+
+pub fn get(day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|g| g.day == day)
+}
+
+fn lines<T: Display>(items: impl IntoIterator<Item = T>) -> String {
+    items.into_iter().fold(String::new(), |mut input, item| {
+        writeln!(input, "{}", item).unwrap();
+        input
+    })
+}
+
+fn letter(rng: &mut Random) -> char {
+    (b'a' + rng.gen_range(0..26)) as char
+}
+
+// size: number of entries, two and three of them sum to 2020
+fn day1(rng: &mut Random, size: usize) -> String {
+    let size = size.max(5);
+    let mut entries: Vec<u32> = (0..size - 5).map(|_| rng.gen_range(1..2020)).collect();
+    let a = rng.gen_range(1..2020);
+    entries.extend(&[a, 2020 - a]);
+    let b = rng.gen_range(1..2019);
+    let c = rng.gen_range(1..2020 - b);
+    entries.extend(&[b, c, 2020 - b - c]);
+    entries.shuffle(rng);
+    lines(entries)
+}
+
+// size: number of passwords
+fn day2(rng: &mut Random, size: usize) -> String {
+    lines((0..size.max(1)).map(|_| {
+        let len = rng.gen_range(3..=20);
+        let first = rng.gen_range(1..len);
+        let second = rng.gen_range(first + 1..=len);
+        let letter = self::letter(rng);
+        // the policy letter is more common than any other letter
+        let password: String = (0..len)
+            .map(|_| {
+                if rng.gen_bool(0.4) {
+                    letter
+                } else {
+                    self::letter(rng)
+                }
+            })
+            .collect();
+        format!("{}-{} {}: {}", first, second, letter, password)
+    }))
+}
+
+// size: number of rows
+fn day3(rng: &mut Random, size: usize) -> String {
+    lines((0..size.max(1)).map(|_| {
+        (0..31)
+            .map(|_| if rng.gen_bool(0.25) { '#' } else { '.' })
+            .collect::<String>()
+    }))
+}
+
+// size: number of passports, some of them with missing or invalid fields
+fn day4(rng: &mut Random, size: usize) -> String {
+    let passports: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let valid = rng.gen_bool(0.8);
+            let mut fields = Vec::new();
+            for &key in PASSPORT_FIELDS.iter() {
+                if rng.gen_bool(if key == "cid" { 0.5 } else { 0.9 }) {
+                    let valid = valid || rng.gen_bool(0.7);
+                    fields.push(format!("{}:{}", key, passport_value(rng, key, valid)));
+                }
+            }
+            fields.shuffle(rng);
+            let mut passport = String::new();
+            for (i, field) in fields.iter().enumerate() {
+                if i > 0 {
+                    passport.push(if rng.gen_bool(0.3) { '\n' } else { ' ' });
+                }
+                passport.push_str(field);
+            }
+            passport
+        })
+        .filter(|passport| !passport.is_empty())
+        .collect();
+    passports.join("\n\n") + "\n"
+}
+
+static PASSPORT_FIELDS: [&str; 8] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"];
+
+fn passport_value(rng: &mut Random, key: &str, valid: bool) -> String {
+    let year = |rng: &mut Random, min: u16, max: u16| {
+        if valid {
+            rng.gen_range(min..=max)
+        } else if rng.gen_bool(0.5) {
+            rng.gen_range(min - 20..min)
+        } else {
+            rng.gen_range(max + 1..max + 20)
+        }
+    };
+    match (key, valid) {
+        ("byr", _) => year(rng, 1920, 2002).to_string(),
+        ("iyr", _) => year(rng, 2010, 2020).to_string(),
+        ("eyr", _) => year(rng, 2020, 2030).to_string(),
+        ("hgt", true) if rng.gen_bool(0.5) => format!("{}cm", rng.gen_range(150..=193)),
+        ("hgt", true) => format!("{}in", rng.gen_range(59..=76)),
+        ("hgt", false) => rng.gen_range(100..200).to_string(),
+        ("hcl", _) => {
+            let color = format!("{:06x}", rng.gen_range(0..0x1000000));
+            if valid {
+                format!("#{}", color)
+            } else {
+                color
+            }
+        }
+        ("ecl", true) => ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"]
+            .choose(rng)
+            .unwrap()
+            .to_string(),
+        ("ecl", false) => ["xry", "zzz", "red"].choose(rng).unwrap().to_string(),
+        ("pid", true) => format!("{:09}", rng.gen_range(0..1_000_000_000)),
+        ("pid", false) => format!("{:010}", rng.gen_range(0..10_000_000_000_u64)),
+        _ => rng.gen_range(60..350).to_string(),
+    }
+}
+
+// size: number of boarding passes, the seat ids are consecutive except for one
+fn day5(rng: &mut Random, size: usize) -> String {
+    let size = size.clamp(2, 1000);
+    let first = rng.gen_range(0..=1023 - size);
+    let missing = first + rng.gen_range(1..=size - 1);
+    let mut ids: Vec<_> = (first..=first + size).filter(|&id| id != missing).collect();
+    ids.shuffle(rng);
+    lines(ids.into_iter().map(|id| {
+        let row = (0..7)
+            .rev()
+            .map(|bit| if id >> 3 >> bit & 1 == 1 { 'B' } else { 'F' });
+        let column = (0..3)
+            .rev()
+            .map(|bit| if id >> bit & 1 == 1 { 'R' } else { 'L' });
+        row.chain(column).collect::<String>()
+    }))
+}
+
+// size: number of groups
+fn day6(rng: &mut Random, size: usize) -> String {
+    let groups: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let mut alphabet: Vec<char> = ('a'..='z').collect();
+            alphabet.shuffle(rng);
+            // letters up to `common` are answered by everyone
+            let common = rng.gen_range(0..5);
+            let people = rng.gen_range(1..=5);
+            let forms: Vec<String> = (0..people)
+                .map(|_| {
+                    let mut form = alphabet[..common].to_vec();
+                    let extra = rng.gen_range(usize::from(common == 0)..8);
+                    form.extend(alphabet[common..].choose_multiple(rng, extra));
+                    form.shuffle(rng);
+                    form.into_iter().collect()
+                })
+                .collect();
+            forms.join("\n")
+        })
+        .collect();
+    groups.join("\n\n") + "\n"
+}
+
+static ADJECTIVES: [&str; 33] = [
+    "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored",
+    "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy", "dusky", "bold",
+    "soft", "deep", "worn", "frosted", "glossy", "hazy", "misty", "rough", "silky", "sleek",
+    "smooth", "stark", "vivid",
+];
+static COLORS: [&str; 33] = [
+    "aqua",
+    "beige",
+    "black",
+    "blue",
+    "bronze",
+    "brown",
+    "chartreuse",
+    "coral",
+    "crimson",
+    "cyan",
+    "fuchsia",
+    "gold",
+    "gray",
+    "green",
+    "indigo",
+    "lavender",
+    "lime",
+    "magenta",
+    "maroon",
+    "olive",
+    "orange",
+    "plum",
+    "purple",
+    "red",
+    "salmon",
+    "silver",
+    "tan",
+    "teal",
+    "tomato",
+    "turquoise",
+    "violet",
+    "white",
+    "yellow",
+];
+
+// size: number of bag colors. The rules are layered, bags only contain bags of the next layer, so
+// the nesting depth stays like the real input. Shiny gold is in the third layer.
+fn day7(rng: &mut Random, size: usize) -> String {
+    const LAYERS: usize = 7;
+    let size = size.max(LAYERS);
+    let mut names: Vec<String> = Vec::new();
+    for round in 0.. {
+        for adjective in ADJECTIVES.iter() {
+            for color in COLORS.iter() {
+                let name = match round {
+                    0 => format!("{} {}", adjective, color),
+                    _ => format!("{} {}{}", adjective, color, round),
+                };
+                if name != "shiny gold" && !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+        if names.len() >= size {
+            break;
+        }
+    }
+    names.shuffle(rng);
+    names.truncate(size - 1);
+    names.insert(2, "shiny gold".to_string());
+    // every layer has at least one bag, the first bags make sure of it
+    let layers: Vec<Vec<&str>> = (0..LAYERS)
+        .map(|layer| {
+            names
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| {
+                    if *i < LAYERS {
+                        *i == layer
+                    } else {
+                        i % LAYERS == layer
+                    }
+                })
+                .map(|(_, name)| name.as_str())
+                .collect()
+        })
+        .collect();
+
+    let mut rules: Vec<String> = Vec::new();
+    for (layer, bags) in layers.iter().enumerate() {
+        for bag in bags {
+            let inner = match layers.get(layer + 1) {
+                Some(next) => {
+                    let count = rng.gen_range(0..=4);
+                    next.choose_multiple(rng, count).collect()
+                }
+                None => Vec::new(),
+            };
+            let contents: Vec<String> = inner
+                .into_iter()
+                .map(|inner| match rng.gen_range(1..=5) {
+                    1 => format!("1 {} bag", inner),
+                    count => format!("{} {} bags", count, inner),
+                })
+                .collect();
+            let contents = match contents.is_empty() {
+                true => "no other bags".to_string(),
+                false => contents.join(", "),
+            };
+            rules.push(format!("{} bags contain {}.", bag, contents));
+        }
+    }
+    rules.shuffle(rng);
+    lines(rules)
+}
+
+// size: number of instructions. The program loops, patching one instruction makes it terminate.
+fn day8(rng: &mut Random, size: usize) -> String {
+    let n = size.max(2);
+    // A terminating program first, jumps only go forward. All jumps stay within the program, also
+    // when the solution patches an instruction.
+    let mut ops: Vec<(&str, isize)> = (0..n)
+        .map(|i| {
+            let forward = rng.gen_range(1..=(n - i).min(20)) as isize;
+            let backward = -(rng.gen_range(0..=i.min(20)) as isize);
+            match rng.gen_range(0..10) {
+                0..=4 => ("acc", rng.gen_range(-50..=50)),
+                5..=7 => ("jmp", forward),
+                _ if rng.gen_bool(0.5) => ("nop", forward),
+                _ => ("nop", backward),
+            }
+        })
+        .collect();
+    let mut executed = Vec::new();
+    let mut ip = 0;
+    while ip < n {
+        executed.push(ip);
+        ip = match ops[ip] {
+            ("jmp", offset) => (ip as isize + offset) as usize,
+            _ => ip + 1,
+        };
+    }
+    // An executed instruction jumps back to one executed before it, which loops. Patching it to a
+    // nop terminates again, because all other jumps go forward.
+    let broken = rng.gen_range(0..executed.len());
+    let target = executed[rng.gen_range(0..=broken)];
+    let broken = executed[broken];
+    ops[broken] = ("jmp", target as isize - broken as isize);
+    lines(ops.into_iter().map(|(op, arg)| format!("{} {:+}", op, arg)))
+}
+
+// size: number of numbers. The first number which is not the sum of two of the 25 numbers before
+// it is the sum of a contiguous range of numbers. The numbers grow exponentially, so there are at
+// most 1000 of them.
+fn day9(rng: &mut Random, size: usize) -> String {
+    const PREAMBLE: usize = 25;
+    let size = size.clamp(PREAMBLE + 3, 1000);
+    let mut numbers: Vec<u64> = (1..=2 * PREAMBLE as u64).collect();
+    numbers.shuffle(rng);
+    numbers.truncate(PREAMBLE);
+    let invalid_at = rng.gen_range(size / 2..size).max(PREAMBLE + 2);
+    for i in PREAMBLE..size {
+        let window = &numbers[i - PREAMBLE..i];
+        let is_sum =
+            |n: u64| (0..PREAMBLE).any(|a| (a + 1..PREAMBLE).any(|b| window[a] + window[b] == n));
+        let number = if i == invalid_at {
+            // the numbers grow, so ranges of older numbers mostly sum up to less than any two
+            // numbers of the window
+            loop {
+                let start = rng.gen_range(0..i / 2);
+                let end = rng.gen_range(start + 2..=(start + 17).min(i));
+                let sum = numbers[start..end].iter().sum();
+                if !is_sum(sum) {
+                    break sum;
+                }
+            }
+        } else {
+            let a = rng.gen_range(0..PREAMBLE);
+            let b = (a + rng.gen_range(1..PREAMBLE)) % PREAMBLE;
+            window[a] + window[b]
+        };
+        numbers.push(number);
+    }
+    lines(numbers)
+}
+
+// size: number of adapters. Runs of adapters one jolt apart are separated by gaps of three jolts,
+// like in the real input. The number of arrangements fits into 64 bits.
+fn day10(rng: &mut Random, size: usize) -> String {
+    // arrangements of a run with the given number of one jolt differences between fixed ends
+    const RUN_ARRANGEMENTS: [u64; 5] = [1, 1, 2, 4, 7];
+    let size = size.max(1);
+    let mut adapters = Vec::new();
+    let mut arrangements: u64 = 1;
+    let mut jolts = 0;
+    while adapters.len() < size {
+        let mut run = rng.gen_range(0..=4).min(size - adapters.len() - 1);
+        while arrangements
+            .checked_mul(RUN_ARRANGEMENTS[run])
+            .is_none_or(|a| a > u64::MAX / 8)
+        {
+            run -= 1;
+        }
+        arrangements *= RUN_ARRANGEMENTS[run];
+        for step in std::iter::once(3).chain(std::iter::repeat_n(1, run)) {
+            jolts += step;
+            adapters.push(jolts);
+        }
+    }
+    adapters.shuffle(rng);
+    lines(adapters)
+}
+
+// size: number of rows and columns. Some seatings never settle but alternate between two states,
+// the seats which keep changing are replaced by floor until both rules of the puzzle settle.
+fn day11(rng: &mut Random, size: usize) -> String {
+    let size = size.max(1);
+    let mut seats: Vec<bool> = (0..size * size).map(|_| rng.gen_bool(0.8)).collect();
+    loop {
+        let alternating: Vec<usize> = [(false, 4), (true, 5)]
+            .iter()
+            .flat_map(|&(visible, tolerance)| alternating_seats(&seats, size, visible, tolerance))
+            .collect();
+        if alternating.is_empty() {
+            break;
+        }
+        alternating.into_iter().for_each(|i| seats[i] = false);
+    }
+    lines(seats.chunks(size).map(|row| {
+        row.iter()
+            .map(|&seat| if seat { 'L' } else { '.' })
+            .collect::<String>()
+    }))
+}
+
+// The seats which alternate once the seating rules of day 11 ran into a cycle, either with the
+// adjacent or the first visible seats. The rules settle or alternate between two states.
+fn alternating_seats(seats: &[bool], size: usize, visible: bool, tolerance: usize) -> Vec<usize> {
+    const DIRECTIONS: [(isize, isize); 8] = [
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+        (0, -1),
+        (0, 1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+    ];
+    let inside =
+        |&(y, x): &(isize, isize)| y >= 0 && x >= 0 && y < size as isize && x < size as isize;
+    // the seats which count for each seat
+    let neighbours: Vec<Vec<usize>> = (0..size * size)
+        .map(|i| {
+            let (y, x) = ((i / size) as isize, (i % size) as isize);
+            DIRECTIONS
+                .iter()
+                .filter_map(|(dy, dx)| {
+                    let mut steps = (1..)
+                        .map(|n| (y + n * dy, x + n * dx))
+                        .take_while(inside)
+                        .map(|(y, x)| y as usize * size + x as usize);
+                    match visible {
+                        true => steps.find(|&i| seats[i]),
+                        false => steps.next().filter(|&i| seats[i]),
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    let round = |taken: &[bool]| -> Vec<bool> {
+        (0..size * size)
+            .map(|i| {
+                let around = neighbours[i].iter().filter(|&&n| taken[n]).count();
+                seats[i]
+                    && if taken[i] {
+                        around < tolerance
+                    } else {
+                        around == 0
+                    }
+            })
+            .collect()
+    };
+    let (mut before, mut taken) = (Vec::new(), vec![false; size * size]);
+    loop {
+        let next = round(&taken);
+        if next == taken {
+            return Vec::new();
+        } else if next == before {
+            return (0..size * size).filter(|&i| taken[i] != next[i]).collect();
+        }
+        before = std::mem::replace(&mut taken, next);
+    }
+}
+
+// size: number of instructions
+fn day12(rng: &mut Random, size: usize) -> String {
+    lines((0..size.max(1)).map(|_| {
+        let action = *['N', 'E', 'S', 'W', 'L', 'R', 'F'].choose(rng).unwrap();
+        let value = match action {
+            'L' | 'R' => 90 * rng.gen_range(1..=3),
+            _ => rng.gen_range(1..=100),
+        };
+        format!("{}{}", action, value)
+    }))
+}
+
+// size: number of positions in the schedule. The busses run at distinct prime intervals, like in
+// the real input, so their departures line up eventually. The answer of part 2 stays below 10^15.
+fn day13(rng: &mut Random, size: usize) -> String {
+    let size = size.max(1);
+    let mut primes: Vec<u64> = (13..1000_u64)
+        .filter(|n| (2..*n).take_while(|d| d * d <= *n).all(|d| n % d != 0))
+        .collect();
+    primes.shuffle(rng);
+    let mut product = 1;
+    let intervals: Vec<u64> = primes
+        .into_iter()
+        .filter(|p| {
+            let fits = product * p <= 1_000_000_000_000_000;
+            if fits {
+                product *= p;
+            }
+            fits
+        })
+        .take(size)
+        .collect();
+    let mut schedule = vec!["x".to_string(); size];
+    let positions = rand::seq::index::sample(rng, size, intervals.len());
+    for (position, interval) in positions.into_iter().zip(&intervals) {
+        schedule[position] = interval.to_string();
+    }
+    format!(
+        "{}\n{}\n",
+        rng.gen_range(1_000_000..2_000_000),
+        schedule.join(",")
+    )
+}
+
+// size: number of instructions. Like in the real input every mask floats 4 to 9 bits and is
+// followed by a few writes, so part 2 writes at most 512 addresses per instruction.
+fn day14(rng: &mut Random, size: usize) -> String {
+    let size = size.max(1);
+    let mut program = Vec::with_capacity(size);
+    while program.len() < size {
+        let mut mask: Vec<char> = (0..36).map(|_| if rng.gen() { '1' } else { '0' }).collect();
+        let floating = rng.gen_range(4..=9);
+        for bit in rand::seq::index::sample(rng, mask.len(), floating) {
+            mask[bit] = 'X';
+        }
+        program.push(format!("mask = {}", mask.into_iter().collect::<String>()));
+        for _ in 0..rng.gen_range(1..=6) {
+            let address = rng.gen_range(0..65536);
+            let value = rng.gen_range(0..1_u64 << 36);
+            program.push(format!("mem[{}] = {}", address, value));
+        }
+    }
+    program.truncate(size);
+    lines(program)
+}
+
+// size: number of distinct starting numbers
+fn day15(rng: &mut Random, size: usize) -> String {
+    let size = size.max(1);
+    let mut numbers: Vec<usize> = (0..3 * size).collect();
+    numbers.shuffle(rng);
+    let numbers: Vec<_> = numbers[..size].iter().map(|n| n.to_string()).collect();
+    numbers.join(",") + "\n"
+}
+
+static TICKET_FIELDS: [&str; 20] = [
+    "departure location",
+    "departure station",
+    "departure platform",
+    "departure track",
+    "departure date",
+    "departure time",
+    "arrival location",
+    "arrival station",
+    "arrival platform",
+    "arrival track",
+    "class",
+    "duration",
+    "price",
+    "route",
+    "row",
+    "seat",
+    "train",
+    "type",
+    "wagon",
+    "zone",
+];
+
+// size: number of nearby tickets, about a fifth of them invalid
+//
+// Every field excludes one value in the middle of its ranges. The fields are mapped to columns in
+// a random order, the column of the nth field contains the excluded values of the fields before
+// it. So the first field fits only its own column, the second field two columns and so on, the
+// mapping is unique.
+fn day16(rng: &mut Random, size: usize) -> String {
+    let n = TICKET_FIELDS.len();
+    // the fields in the order of their columns in the staircase
+    let mut fields = TICKET_FIELDS.to_vec();
+    fields.shuffle(rng);
+    let excluded: Vec<usize> = rand::seq::index::sample(rng, 800, n)
+        .into_iter()
+        .map(|v| v + 100)
+        .collect();
+    let mut columns: Vec<usize> = (0..n).collect();
+    columns.shuffle(rng);
+
+    let mut input = String::new();
+    for (name, excluded) in TICKET_FIELDS.iter().map(|name| {
+        let field = fields.iter().position(|f| f == name).unwrap();
+        (name, excluded[field])
+    }) {
+        let (min, max) = (rng.gen_range(25..=50), rng.gen_range(950..=975));
+        writeln!(
+            input,
+            "{}: {}-{} or {}-{}",
+            name,
+            min,
+            excluded - 1,
+            excluded + 1,
+            max
+        )
+        .unwrap();
+    }
+    // values of the column of the field, which fit the field and all fields behind it
+    let value = |rng: &mut Random, field: usize| loop {
+        let value = rng.gen_range(50..=950);
+        if !excluded[field..].contains(&value) {
+            break value;
+        }
+    };
+    let ticket = |rng: &mut Random, values: &dyn Fn(&mut Random, usize) -> usize| {
+        let mut ticket = vec![0; n];
+        for field in 0..n {
+            ticket[columns[field]] = values(rng, field);
+        }
+        ticket
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+
+    writeln!(
+        input,
+        "\nyour ticket:\n{}\n\nnearby tickets:",
+        ticket(rng, &|rng, _| value(rng, 0))
+    )
+    .unwrap();
+    let valid = (size * 4 / 5).max(n);
+    let mut tickets: Vec<String> = (0..valid)
+        .map(|t| {
+            ticket(rng, &|rng, field| {
+                if t < field {
+                    excluded[t]
+                } else {
+                    value(rng, field)
+                }
+            })
+        })
+        .collect();
+    for _ in valid..size {
+        // a value which fits no field at all
+        let invalid = rng.gen_range(0..n);
+        tickets.push(ticket(rng, &|rng, field| match field == invalid {
+            true => rng.gen_range(976..1000),
+            false => value(rng, field),
+        }));
+    }
+    tickets.shuffle(rng);
+    input + &lines(tickets)
+}
+
+// size: number of rows and columns of the initial slice
+fn day17(rng: &mut Random, size: usize) -> String {
+    lines((0..size.max(1)).map(|_| {
+        (0..size.max(1))
+            .map(|_| if rng.gen_bool(0.5) { '#' } else { '.' })
+            .collect::<String>()
+    }))
+}
+
+// size: number of expressions. The results stay below 10^12 for any precedence of the operators.
+fn day18(rng: &mut Random, size: usize) -> String {
+    lines((0..size.max(1)).map(|_| loop {
+        let (expression, bound) = expression(rng, 2);
+        if bound.is_some_and(|b| b < 1_000_000_000_000) {
+            break expression;
+        }
+    }))
+}
+
+// A random expression and an upper bound of its value, if the bound fits into 64 bits. For
+// operands of at least two, adding is never more than multiplying, so the product of the operands
+// bounds the value for any precedence.
+fn expression(rng: &mut Random, depth: usize) -> (String, Option<u64>) {
+    let mut expression = String::new();
+    let mut bound = Some(1_u64);
+    for i in 0..rng.gen_range(2..=5) {
+        if i > 0 {
+            expression.push_str(if rng.gen_bool(0.5) { " + " } else { " * " });
+        }
+        let (operand, value) = if depth > 0 && rng.gen_bool(0.25) {
+            let (inner, value) = self::expression(rng, depth - 1);
+            (format!("({})", inner), value)
+        } else {
+            let digit = rng.gen_range(1..=9);
+            (digit.to_string(), Some(digit))
+        };
+        expression.push_str(&operand);
+        bound = bound
+            .zip(value)
+            .and_then(|(bound, value)| bound.checked_mul(value.max(2)));
+    }
+    (expression, bound)
+}
+
+// size: number of messages
+//
+// Rule 0 is `8 11` with `8: 42` and `11: 42 31` like in the real input, so part 2 can replace
+// them. Rules 42 and 31 match eight characters. Like in the real input, there is only one way to
+// match a message: the alternatives of a rule start with different characters and 42 starts with
+// an `a`, 31 with a `b`. A third of the messages match rule 0, a third only the looping rules of
+// part 2 and the rest are random.
+fn day19(rng: &mut Random, size: usize) -> String {
+    let mut ids: Vec<usize> = (1..140)
+        .filter(|id| ![8, 11, 31, 42].contains(id))
+        .collect();
+    ids.shuffle(rng);
+    let mut ids = ids.into_iter();
+    // the alternative sequences of sub rules per rule, characters have no alternatives
+    let mut patterns: HashMap<usize, Vec<Vec<usize>>> = HashMap::new();
+    let (a, b) = (ids.next().unwrap(), ids.next().unwrap());
+    let mut rules = vec![
+        "0: 8 11".to_string(),
+        "8: 42".to_string(),
+        "11: 42 31".to_string(),
+        format!("{}: \"a\"", a),
+        format!("{}: \"b\"", b),
+    ];
+    // rules matching one character less than the rules of the next level
+    let mut below = vec![a, b];
+    for level in 1..=7 {
+        // the rules of the level with the characters their alternatives start with
+        let level_ids: Vec<(usize, Vec<usize>)> = match level {
+            7 => vec![(42, vec![a]), (31, vec![b])],
+            _ => ids.by_ref().take(4).map(|id| (id, vec![a, b])).collect(),
+        };
+        for (id, first) in &level_ids {
+            let mut alternatives = Vec::new();
+            for &first in first {
+                if alternatives.is_empty() || rng.gen_bool(0.7) {
+                    alternatives.push(vec![first, *below.choose(rng).unwrap()]);
+                }
+            }
+            let pattern: Vec<String> = alternatives
+                .iter()
+                .map(|ids| format!("{} {}", ids[0], ids[1]))
+                .collect();
+            rules.push(format!("{}: {}", id, pattern.join(" | ")));
+            patterns.insert(*id, alternatives);
+        }
+        below = level_ids.into_iter().map(|(id, _)| id).collect();
+    }
+    rules.shuffle(rng);
+
+    // a random message matching the rule
+    let expand = |rng: &mut Random, id: usize, message: &mut String| {
+        let mut stack = vec![id];
+        while let Some(id) = stack.pop() {
+            match patterns.get(&id) {
+                Some(alternatives) => stack.extend(alternatives.choose(rng).unwrap().iter().rev()),
+                None => message.push(if id == a { 'a' } else { 'b' }),
+            }
+        }
+    };
+    let messages = (0..size).map(|_| {
+        let (times_42, times_31) = match rng.gen_range(0..3) {
+            0 => (2, 1),
+            1 => {
+                let times_31 = rng.gen_range(1..=3);
+                (times_31 + rng.gen_range(1..=3), times_31)
+            }
+            _ => {
+                let len = 8 * rng.gen_range(2..=6);
+                return (0..len)
+                    .map(|_| if rng.gen_bool(0.5) { 'a' } else { 'b' })
+                    .collect();
+            }
+        };
+        let mut message = String::new();
+        for _ in 0..times_42 {
+            expand(rng, 42, &mut message);
+        }
+        for _ in 0..times_31 {
+            expand(rng, 31, &mut message);
+        }
+        message
+    });
+    let messages: Vec<String> = messages.collect();
+    format!("{}\n{}", lines(rules), lines(messages))
+}
+
+// size: tiles per side of the image, the real image is 12 by 12 tiles. Neighbouring tiles share
+// their border, every border is unique, so there is a single arrangement up to the orientation of
+// the whole image. Sea monsters are hidden in the image, the sparse background hardly ever forms
+// one by chance.
+fn day20(rng: &mut Random, size: usize) -> String {
+    let side = size.max(1);
+    // enough pixels per border for unique borders and an image wide enough for a sea monster
+    let borders = 2 * side * (side + 1);
+    let bits = (usize::BITS - borders.leading_zeros()) as usize + 2;
+    let width = 10.max(bits + 2).max(20 / side + 3);
+    let (step, inner) = (width - 1, width - 2);
+
+    // the pixels of all tiles, the borders of neighbouring tiles overlap
+    let len = side * step + 1;
+    let mut pixels = Grid::from_fn(len, len, |_, _| rng.gen_bool(0.3));
+    let mut used = HashSet::new();
+    for border in 0..borders {
+        // horizontal borders first, the corners of the tiles stay as they are
+        let (horizontal, border) = (border < borders / 2, border % (borders / 2));
+        let (line, start) = (border / side * step, border % side * step);
+        let position = |i| {
+            if horizontal {
+                (start + i, line)
+            } else {
+                (line, start + i)
+            }
+        };
+        loop {
+            for i in 1..step {
+                let (col, row) = position(i);
+                pixels.set(col, row, rng.gen_bool(0.5));
+            }
+            let cells: Vec<bool> = (0..width)
+                .map(|i| {
+                    let (col, row) = position(i);
+                    *pixels.get(col, row).unwrap()
+                })
+                .collect();
+            // tiles can be flipped, so a border matches its reverse as well
+            let reversed = cells.iter().rev().copied().collect();
+            if used.insert(cells.min(reversed)) {
+                break;
+            }
+        }
+    }
+
+    // sea monsters in image coordinates, where the borders are removed
+    let image_len = side * inner;
+    let monster: Vec<(usize, usize)> = [
+        "                  # ",
+        "#    ##    ##    ###",
+        " #  #  #  #  #  #   ",
+    ]
+    .iter()
+    .enumerate()
+    .flat_map(|(row, line)| line.match_indices('#').map(move |(col, _)| (col, row)))
+    .collect();
+    for _ in 0..(image_len * image_len / 400).max(1) {
+        let (col, row) = (
+            rng.gen_range(0..=image_len - 20),
+            rng.gen_range(0..=image_len - 3),
+        );
+        for &(c, r) in &monster {
+            let to_tile = |i: usize| i / inner * step + 1 + i % inner;
+            pixels.set(to_tile(col + c), to_tile(row + r), true);
+        }
+    }
+
+    let mut ids: Vec<usize> = (1000..1000 + 10 * side * side).collect();
+    ids.shuffle(rng);
+    let mut tiles: Vec<String> = (0..side * side)
+        .map(|i| {
+            let (col, row) = (i % side * step, i / side * step);
+            let tile = pixels.sub_grid(col, row, width, width).unwrap();
+            let tile = tile.orientations().nth(rng.gen_range(0..8)).unwrap();
+            let rows = tile.rows().map(|row| {
+                row.iter()
+                    .map(|&pixel| if pixel { '#' } else { '.' })
+                    .collect::<String>()
+            });
+            format!("Tile {}:\n{}", ids[i], lines(rows))
+        })
+        .collect();
+    tiles.shuffle(rng);
+    tiles.join("\n")
+}
+
+// size: number of foods. Each allergen is in one ingredient, foods are added until the allergens
+// can be told apart by elimination, so small inputs may list a few more foods.
+fn day21(rng: &mut Random, size: usize) -> String {
+    let mut ingredients = HashSet::new();
+    while ingredients.len() < (5 * size).max(20) {
+        let len = rng.gen_range(4..=8);
+        ingredients.insert((0..len).map(|_| letter(rng)).collect::<String>());
+    }
+    let mut ingredients: Vec<String> = ingredients.into_iter().collect();
+    ingredients.sort_unstable();
+    ingredients.shuffle(rng);
+    let allergens = [
+        "dairy",
+        "eggs",
+        "fish",
+        "nuts",
+        "peanuts",
+        "sesame",
+        "shellfish",
+        "soy",
+        "wheat",
+    ];
+    let allergens = &allergens[..rng.gen_range(5..=allergens.len())];
+
+    // ingredients and listed allergens by index, the first ingredients contain an allergen each
+    let mut foods: Vec<(Vec<usize>, Vec<usize>)> = Vec::new();
+    loop {
+        let mut candidates: BTreeMap<usize, HashSet<usize>> = BTreeMap::new();
+        for (food, listed) in &foods {
+            let food: HashSet<usize> = food.iter().copied().collect();
+            for &allergen in listed {
+                let candidates = candidates.entry(allergen).or_insert_with(|| food.clone());
+                candidates.retain(|i| food.contains(i));
+            }
+        }
+        if foods.len() >= size.max(1) && matching::eliminate(candidates).is_complete() {
+            break;
+        }
+        let count = rng.gen_range(1..=ingredients.len() / 3);
+        let mut food = rand::seq::index::sample(rng, ingredients.len(), count).into_vec();
+        food.sort_unstable();
+        // allergens are not always listed
+        let listed = (0..allergens.len())
+            .filter(|allergen| food.contains(allergen) && rng.gen_bool(0.7))
+            .collect();
+        food.shuffle(rng);
+        foods.push((food, listed));
+    }
+
+    lines(foods.into_iter().map(|(food, listed)| {
+        let food: Vec<&str> = food.iter().map(|&i| ingredients[i].as_str()).collect();
+        let listed: Vec<&str> = listed.into_iter().map(|a| allergens[a]).collect();
+        if listed.is_empty() {
+            food.join(" ")
+        } else {
+            format!("{} (contains {})", food.join(" "), listed.join(", "))
+        }
+    }))
+}
+
+// size: number of cards per player, at most 127 because cards are single bytes. Games of Combat
+// can loop forever, decks are dealt until the game ends.
+fn day22(rng: &mut Random, size: usize) -> String {
+    let size = size.clamp(1, 127);
+    loop {
+        let mut cards: Vec<usize> = (1..=2 * size).collect();
+        cards.shuffle(rng);
+        let (one, two) = cards.split_at(size);
+        if combat_ends(one, two) {
+            return format!("Player 1:\n{}\nPlayer 2:\n{}", lines(one), lines(two));
+        }
+    }
+}
+
+fn combat_ends(one: &[usize], two: &[usize]) -> bool {
+    let mut one: VecDeque<_> = one.iter().copied().collect();
+    let mut two: VecDeque<_> = two.iter().copied().collect();
+    for _ in 0..100_000 {
+        match (one.pop_front(), two.pop_front()) {
+            (Some(a), Some(b)) if a > b => one.extend(&[a, b]),
+            (Some(a), Some(b)) => two.extend(&[b, a]),
+            _ => return true,
+        }
+    }
+    false
+}
+
+// size: number of cups, at most 9 because labels are single digits
+fn day23(rng: &mut Random, size: usize) -> String {
+    let mut labels: Vec<usize> = (1..=size.clamp(5, 9)).collect();
+    labels.shuffle(rng);
+    let labels: String = labels.iter().map(|l| l.to_string()).collect();
+    labels + "\n"
+}
+
+// size: number of paths, each one takes 15 to 25 steps from the reference tile
+fn day24(rng: &mut Random, size: usize) -> String {
+    lines((0..size.max(1)).map(|_| {
+        let steps = rng.gen_range(15..=25);
+        (0..steps)
+            .map(|_| *["e", "se", "sw", "w", "nw", "ne"].choose(rng).unwrap())
+            .collect::<String>()
+    }))
+}
+
+// size: largest loop size of the card and the door
+fn day25(rng: &mut Random, size: usize) -> String {
+    const MODULUS: u64 = 20201227;
+    let public_key = |loop_size: u64| (0..loop_size).fold(1, |value, _| value * 7 % MODULUS);
+    let max = (size as u64).clamp(1, MODULUS - 2);
+    let card = public_key(rng.gen_range(1..=max));
+    let door = public_key(rng.gen_range(1..=max));
+    format!("{}\n{}\n", card, door)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::{self, DAYS};
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_every_day_has_a_generator() {
+        for day in DAYS {
+            assert!(get(day.day).is_some(), "day {}", day.day);
+        }
+    }
+
+    #[test]
+    fn test_generated_inputs_are_solvable() {
+        for generator in GENERATORS {
+            let day = days::get(generator.day).unwrap();
+            // the second parts of days 15 and 23 take minutes without optimizations
+            let parts: &[u8] = match generator.day {
+                15 | 23 => &[1],
+                _ => &[1, 2],
+            };
+            // the smallest sizes are raised to the smallest solvable input
+            for size in &[0, 1, (generator.default_size / 4).max(1)] {
+                for seed in 0..3 {
+                    let input = (generator.generate)(&mut Random::seed_from_u64(seed), *size);
+                    if let Err(e) = (day.solve)(&input, parts) {
+                        panic!(
+                            "day {} size {} seed {}: {}\n{}",
+                            generator.day, size, seed, e, input
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_same_seed_same_input() {
+        for generator in GENERATORS {
+            let generate = |seed| (generator.generate)(&mut Random::seed_from_u64(seed), 10);
+            assert_eq!(generate(7), generate(7));
+        }
+    }
+}
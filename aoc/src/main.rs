@@ -0,0 +1,365 @@
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use structopt::StructOpt;
+use thiserror::Error;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::EnvFilter;
+
+mod alloc;
+mod answers;
+mod compare;
+mod days;
+mod generate;
+mod output;
+
+use alloc::{CountingAllocator, Measurement};
+use answers::{Answers, Status};
+use compare::Report;
+use days::{Day, Solved};
+use helpers::input::{self, InputError, InputSource};
+use output::{Format, Record};
+use rand::SeedableRng;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Advent of Code 2020 solutions
+///
+/// Puzzle inputs and answers are read from the `assets` directory of the workspace, a different
+/// directory can be set with `AOC_ASSETS_DIR`
+#[derive(Debug, StructOpt)]
+struct Options {
+    /// Trace the solutions on stderr, `-v` reports the phases, `-vv` and `-vvv` add the debug and
+    /// trace events of the days. `RUST_LOG` takes precedence, e.g. `RUST_LOG=day22=trace`
+    #[structopt(short, long, parse(from_occurrences))]
+    verbose: u8,
+    #[structopt(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, StructOpt)]
+enum Command {
+    /// Solve the puzzles of one or all days
+    Run {
+        /// Day to solve, all days are solved if omitted
+        day: Option<u8>,
+        /// Only solve the given part
+        #[structopt(short, long, possible_values = &["1", "2"])]
+        part: Option<u8>,
+        /// Puzzle input file, `-` reads from stdin [default: {assets}/days/day{day}.txt]
+        #[structopt(short, long, parse(from_os_str))]
+        input: Option<PathBuf>,
+        /// Report wall time and peak heap usage of parsing and each part
+        #[structopt(short, long)]
+        time: bool,
+        /// Solve part 1 while reading the input, instead of reading all of it first, e.g. from a
+        /// pipe. Only day 9 supports it
+        #[structopt(long, requires = "day")]
+        stream: bool,
+        /// Print one json record per answer with its duration and details, instead of text
+        #[structopt(long, default_value = "text", possible_values = &["text", "json"])]
+        format: Format,
+    },
+    /// Check the answers for the puzzle inputs against the expected answers
+    Verify {
+        /// Day to check, all days are checked if omitted
+        day: Option<u8>,
+        /// File with the expected answers per day and part [default: {assets}/answers.toml]
+        #[structopt(short, long, parse(from_os_str))]
+        answers: Option<PathBuf>,
+    },
+    /// Download the puzzle input of a day, unless it is already in `{assets}/days`
+    ///
+    /// The session token is read from `AOC_SESSION`, a different puzzle server can be set with
+    /// `AOC_BASE_URL`
+    Fetch {
+        /// Day to fetch
+        day: u8,
+    },
+    /// Generate a random puzzle input, every part of it can be solved
+    ///
+    /// The same day, size and seed always generate the same input, e.g. to benchmark larger inputs
+    /// with `aoc generate 16 --size 100000 | aoc run 16 --input - --time`
+    Generate {
+        /// Day to generate an input for
+        day: u8,
+        /// Size of the input, e.g. the number of lines, what is counted differs per day [default:
+        /// about the size of the real input]
+        #[structopt(short, long)]
+        size: Option<usize>,
+        /// Seed of the random number generator
+        #[structopt(long, default_value = "0")]
+        seed: u64,
+        /// File to write the input to, instead of stdout
+        #[structopt(short, long, parse(from_os_str))]
+        output: Option<PathBuf>,
+    },
+    /// Compare two saved benchmark baselines as markdown table
+    ///
+    /// Baselines are saved with `cargo bench -p aoc -- --save-baseline <name>`
+    Compare {
+        /// Baseline to compare against
+        old: String,
+        /// Baseline with the changes
+        new: String,
+        /// Output directory of criterion [default: `criterion` in `CARGO_TARGET_DIR` or in the
+        /// `target` directory of the workspace]
+        #[structopt(long, parse(from_os_str))]
+        criterion_dir: Option<PathBuf>,
+    },
+}
+
+// Errors are printed with Display, parse errors render the failing part of the input
+fn main() {
+    let options = Options::from_args();
+    init_tracing(options.verbose);
+    if let Err(e) = execute(options.command) {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+// Tracing is silent unless asked for, the answers on stdout stay untouched
+fn init_tracing(verbose: u8) {
+    let level = match verbose {
+        0 => LevelFilter::OFF,
+        1 => LevelFilter::INFO,
+        2 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    };
+    let filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(level.to_string()));
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(std::io::stderr)
+        .init();
+}
+
+fn execute(command: Command) -> Result<(), Box<dyn Error>> {
+    match command {
+        Command::Run {
+            day,
+            part,
+            input,
+            time,
+            stream,
+            format,
+        } => run(day, part, input, time, stream, format),
+        Command::Verify { day, answers } => {
+            let answers = answers.unwrap_or_else(|| input::assets_dir().join("answers.toml"));
+            verify(day, &answers)
+        }
+        Command::Fetch { day } => {
+            let source = InputSource::from_env(input::days_dir())?;
+            source.get(day)?;
+            println!(
+                "Input of day {} is in {}",
+                day,
+                source.cache_path(day).display()
+            );
+            Ok(())
+        }
+        Command::Generate {
+            day,
+            size,
+            seed,
+            output,
+        } => {
+            let generator = generate::get(day).ok_or(RunnerError::UnknownDay(day))?;
+            let size = size.unwrap_or(generator.default_size);
+            let input = (generator.generate)(&mut generate::Random::seed_from_u64(seed), size);
+            match output {
+                Some(path) => std::fs::write(path, input)?,
+                None => print!("{}", input),
+            }
+            Ok(())
+        }
+        Command::Compare {
+            old,
+            new,
+            criterion_dir,
+        } => {
+            let criterion_dir = criterion_dir.unwrap_or_else(compare::default_dir);
+            let comparisons = compare::compare(&criterion_dir, &old, &new)?;
+            let report = Report {
+                old: &old,
+                new: &new,
+                comparisons: &comparisons,
+            };
+            print!("{}", report);
+            Ok(())
+        }
+    }
+}
+
+fn run(
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    time: bool,
+    stream: bool,
+    format: Format,
+) -> Result<(), Box<dyn Error>> {
+    let days = match day {
+        Some(day) => vec![days::get(day).ok_or(RunnerError::UnknownDay(day))?],
+        // a single input can't be shared among all days
+        None if input.is_some() => return Err(RunnerError::InputWithoutDay.into()),
+        None => days::DAYS.iter().collect(),
+    };
+    let parts = match part {
+        Some(part) => vec![part],
+        // only part 1 can be streamed
+        None if stream => vec![1],
+        None => vec![1, 2],
+    };
+
+    for day in days {
+        let solved = if stream {
+            solve_streamed(day, &parts, input.as_deref())
+        } else {
+            solve(day, &parts, input.as_deref())
+        };
+        let solved = match solved {
+            // days without an input, e.g. before it is fetched, are skipped like in `verify`
+            Err(e) if matches!(e.downcast_ref(), Some(InputError::MissingDay(..))) => {
+                if format == Format::Text {
+                    for part in &parts {
+                        println!("Day {} Part {}: no input", day.day, part);
+                    }
+                }
+                continue;
+            }
+            solved => solved?,
+        };
+        if format == Format::Json {
+            // the records already contain the durations
+            for answer in &solved.answers {
+                println!("{}", serde_json::to_string(&Record::new(day.day, answer))?);
+            }
+            continue;
+        }
+        for answer in &solved.answers {
+            println!("Day {} Part {}: {}", day.day, answer.part, answer.answer);
+        }
+        if time {
+            if let Some(parse) = &solved.parse {
+                print_measurement("parse", parse);
+            }
+            for answer in &solved.answers {
+                print_measurement(&format!("part {}", answer.part), &answer.measurement);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn solve(day: &Day, parts: &[u8], input: Option<&Path>) -> Result<Solved, Box<dyn Error>> {
+    let input = match input {
+        Some(path) => input::read(path)?,
+        None => input::read_day(day.day)?,
+    };
+    (day.solve)(&input, parts)
+}
+
+fn solve_streamed(day: &Day, parts: &[u8], input: Option<&Path>) -> Result<Solved, Box<dyn Error>> {
+    let stream = match (day.stream, parts) {
+        (Some(stream), [1]) => stream,
+        _ => return Err(RunnerError::NoStream(day.day).into()),
+    };
+    let mut reader = match input {
+        Some(path) => input::open(path)?,
+        None => input::open_day(day.day)?,
+    };
+    Ok(Solved {
+        parse: None,
+        answers: vec![stream(&mut reader)?],
+    })
+}
+
+fn print_measurement(phase: &str, measurement: &Measurement) {
+    println!(
+        "  {:<8} {:>12.3?}  peak heap {:>10}",
+        phase,
+        measurement.duration,
+        format_bytes(measurement.peak_heap)
+    );
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+fn verify(day: Option<u8>, answers: &Path) -> Result<(), Box<dyn Error>> {
+    let days = match day {
+        Some(day) => vec![days::get(day).ok_or(RunnerError::UnknownDay(day))?],
+        None => days::DAYS.iter().collect(),
+    };
+    let expected = Answers::from_file(answers)?;
+
+    let mut failed = 0;
+    println!("Day Part  Status   Answer               Expected");
+    for day in days {
+        // a broken day is reported as failure, the remaining days are still checked
+        let answers = match input::read_day(day.day) {
+            // there is nothing to check before the input is fetched, unless answers are expected
+            Err(InputError::MissingDay(..)) => vec!["no input".to_string(); 2],
+            input => input
+                .map_err(Box::<dyn Error>::from)
+                .and_then(|input| (day.solve)(&input, &[1, 2]))
+                .map(|solved| solved.answers.into_iter().map(|a| a.answer).collect())
+                // only the first line of an error fits into the table
+                .unwrap_or_else(|e| {
+                    let e = e.to_string();
+                    vec![format!("error: {}", e.lines().next().unwrap_or_default()); 2]
+                }),
+        };
+        for (part, answer) in (1..=2).zip(answers) {
+            let status = match expected.check(day.day, part, &answer) {
+                // errors never pass, even if the answer is missing
+                Status::Missing if answer.starts_with("error: ") => Status::Fail,
+                status => status,
+            };
+            if status == Status::Fail {
+                failed += 1;
+            }
+            let expected = expected
+                .get(day.day, part)
+                .map_or_else(String::new, |e| e.to_string());
+            let row = format!(
+                "{:>3} {:>4}  {:<7}  {:<20} {}",
+                day.day, part, status, answer, expected
+            );
+            println!("{}", row.trim_end());
+        }
+    }
+
+    if failed > 0 {
+        return Err(RunnerError::WrongAnswers(failed).into());
+    }
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, Error)]
+enum RunnerError {
+    #[error("there is no solution for day {0}")]
+    UnknownDay(u8),
+    #[error("an input can only be given together with a day")]
+    InputWithoutDay,
+    #[error("day {0} can't be solved while streaming its input, only part 1 of day 9 can")]
+    NoStream(u8),
+    #[error("{0} answers did not match the expected answers")]
+    WrongAnswers(usize),
+}
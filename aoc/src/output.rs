@@ -0,0 +1,116 @@
+use crate::days::Answer;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::str::FromStr;
+use thiserror::Error;
+
+// How answers are printed by `run`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    // one human readable line per answer
+    Text,
+    // one json record per line and answer, e.g.
+    // {"day":11,"part":1,"answer":2483,"duration_ms":12.5,"details":{"iterations":103}}
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = UnknownFormat;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(UnknownFormat(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("unknown format {0:?}, expected text or json")]
+pub struct UnknownFormat(String);
+
+#[derive(Debug, Serialize)]
+pub struct Record {
+    day: u8,
+    part: u8,
+    answer: Value,
+    duration_ms: f64,
+    // only present for answers with details
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details: Option<Map<String, Value>>,
+}
+
+impl Record {
+    pub fn new(day: u8, answer: &Answer) -> Self {
+        let details: Map<_, _> = answer
+            .details
+            .iter()
+            .map(|(name, detail)| (name.to_string(), value(detail)))
+            .collect();
+        Self {
+            day,
+            part: answer.part,
+            answer: value(&answer.answer),
+            duration_ms: answer.measurement.duration.as_secs_f64() * 1000.0,
+            details: if details.is_empty() {
+                None
+            } else {
+                Some(details)
+            },
+        }
+    }
+}
+
+// Numbers stay numbers, so dashboards can aggregate them, everything else becomes a string
+fn value(s: &str) -> Value {
+    if let Ok(n) = s.parse::<u64>() {
+        Value::from(n)
+    } else if let Ok(n) = s.parse::<i64>() {
+        Value::from(n)
+    } else {
+        Value::from(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alloc::Measurement;
+    use std::time::Duration;
+
+    fn answer(answer: &str, details: Vec<(&'static str, String)>) -> Answer {
+        Answer {
+            part: 2,
+            answer: answer.to_string(),
+            details,
+            measurement: Measurement {
+                duration: Duration::from_micros(1500),
+                peak_heap: 0,
+            },
+        }
+    }
+
+    #[test]
+    fn test_record() {
+        let record = Record::new(8, &answer("920", vec![("substituted_index", "7".into())]));
+        assert_eq!(
+            r#"{"day":8,"part":2,"answer":920,"duration_ms":1.5,"details":{"substituted_index":7}}"#,
+            serde_json::to_string(&record).unwrap()
+        );
+
+        let record = Record::new(23, &answer("-12", vec![]));
+        assert_eq!(
+            r#"{"day":23,"part":2,"answer":-12,"duration_ms":1.5}"#,
+            serde_json::to_string(&record).unwrap()
+        );
+        let record = Record::new(23, &answer("unsolved", vec![]));
+        assert_eq!(Value::from("unsolved"), record.answer);
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(Format::Json, "json".parse().unwrap());
+        assert!("yaml".parse::<Format>().is_err());
+    }
+}
@@ -0,0 +1,68 @@
+use helpers::{parse_lines, ParseError, Solution};
+use itertools::Itertools;
+use std::num::ParseIntError;
+use thiserror::Error;
+use tracing::debug;
+
+// Specifically, they need you to find the entries that sum to 2020
+static TARGET: usize = 2020;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input<'a> = Vec<usize>;
+    type Error = ExpenseError;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        let numbers = parse_lines(input, str::parse)?;
+        debug!(entries = numbers.len(), "expenses");
+        Ok(numbers)
+    }
+
+    // Part 1
+    // Specifically, they need you to find the two entries that sum to 2020 and then multiply those two numbers together.
+    fn part1(numbers: &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        day1(numbers, 2, TARGET)
+    }
+
+    // Part 2
+    // Find _three_ numbers in your expense report that meet the same criteria
+    fn part2(numbers: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        day1(numbers, 3, TARGET)
+    }
+}
+
+fn day1(input: &[usize], n: usize, target: usize) -> Result<usize, ExpenseError> {
+    // Get first n values resulting in sum equals target
+    n_combination_target_sum(input, target, n)
+        .next()
+        // Multiply resulting values with each other
+        .map(|values| {
+            debug!(?values, "combination");
+            values.iter().copied().product()
+        })
+        .ok_or(ExpenseError::NoCombination(n, target))
+}
+
+fn n_combination_target_sum(
+    input: &[usize],
+    target: usize,
+    n: usize,
+) -> impl Iterator<Item = Vec<&usize>> {
+    input
+        .iter()
+        .combinations(n)
+        // filter all combinations, where the sum of the elements is equal to `target`
+        .filter(move |values| values.iter().copied().sum::<usize>() == target)
+}
+
+#[derive(Error, Debug, Clone)]
+pub enum ExpenseError {
+    #[error("could not parse expense, {0}")]
+    ParseExpense(#[from] ParseError<ParseIntError>),
+    #[error("there are no {0} numbers, resulting in {1}")]
+    NoCombination(usize, usize),
+}
@@ -1,37 +1,50 @@
-use helpers::parse_lines_file;
+use helpers::{parse_lines, ParseError, Solution};
 use itertools::Itertools;
 use std::collections::HashMap;
-use std::error::Error;
-
-fn main() -> Result<(), Box<dyn Error>> {
-    let adapters: Vec<Jolts> = parse_lines_file("./assets/days/day10.txt")?;
-
-    let adapters = SortedAdapters::new(adapters);
-    let rating = adapters.chain_output_rating();
-    let (sum1, sum3) = adapters.count_1_3_chain_differences();
-    println!(
-        "Jolt Rating: {:?}, 1s: {}, 3s: {}, product = {}",
-        rating,
-        sum1,
-        sum3,
-        sum1 * sum3
-    );
-
-    let combinations = adapters.count_possible_valid_combinations();
-    println!("Possible Adapter Combinations: {}", combinations);
-    Ok(())
+use std::num::ParseIntError;
+use thiserror::Error;
+use tracing::{debug, trace};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input<'a> = SortedAdapters;
+    type Error = AdapterError;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        SortedAdapters::new(parse_lines(input, str::parse)?)
+    }
+
+    // Part 1
+    // What is the number of 1-jolt differences multiplied by the number of 3-jolt differences?
+    fn part1(adapters: &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        let (sum1, sum3) = adapters.count_1_3_chain_differences();
+        debug!(ones = sum1, threes = sum3, "differences");
+        Ok(sum1 * sum3)
+    }
+
+    // Part 2
+    // What is the total number of distinct ways you can arrange the adapters to connect the
+    // charging outlet to your device?
+    fn part2(adapters: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        Ok(adapters.count_possible_valid_combinations())
+    }
 }
 
 type Jolts = u32;
 const MAX_DIFF: Jolts = 3;
 
 #[derive(Clone, Debug)]
-struct SortedAdapters {
+pub struct SortedAdapters {
     adapters: Vec<Jolts>,
 }
 
 impl SortedAdapters {
-    fn new(mut adapters: Vec<Jolts>) -> Self {
+    fn new(mut adapters: Vec<Jolts>) -> Result<Self, AdapterError> {
+        debug!(adapters = adapters.len(), "adapters");
         // Treat the charging outlet near your seat as having an effective joltage rating of 0.
         adapters.push(0);
         adapters.sort_unstable();
@@ -41,13 +54,13 @@ impl SortedAdapters {
             adapters.push(highest + MAX_DIFF)
         }
         let a = SortedAdapters { adapters };
-        // TODO Some error handling
         if !a.is_chain_possible() {
-            panic! {"something went wrong there is a gap bigger than 3 jolts"}
+            return Err(AdapterError::NoChain);
         }
-        a
+        Ok(a)
     }
 
+    #[cfg(test)]
     fn chain_output_rating(&self) -> Option<Jolts> {
         self.adapters.last().copied()
     }
@@ -99,6 +112,7 @@ impl SortedAdapters {
                 })
                 .sum();
 
+            trace!(jolts, combinations, "arrangements");
             computed_combinations.insert(jolts, combinations);
         }
         self.adapters
@@ -108,15 +122,26 @@ impl SortedAdapters {
     }
 }
 
+#[derive(Clone, Debug, Error)]
+pub enum AdapterError {
+    #[error("could not parse adapter joltage, {0}")]
+    ParseJolts(#[from] ParseError<ParseIntError>),
+    #[error("adapters can't be chained, there is a gap bigger than 3 jolts or a repeated joltage")]
+    NoChain,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use helpers::parse_lines_file;
+    use proptest::prelude::*;
 
     #[test]
     fn test_part1_example_short() {
         let adapters = SortedAdapters::new(
             parse_lines_file("../assets/days/day10_example_short.txt").unwrap(),
-        );
+        )
+        .unwrap();
 
         //With these adapters, your device's built-in joltage adapter would be rated for
         // 19 + 3 = 22 jolts, 3 higher than the highest-rated adapter.
@@ -131,9 +156,11 @@ mod tests {
     fn test_part2_examples() {
         let short_example = SortedAdapters::new(
             parse_lines_file("../assets/days/day10_example_short.txt").unwrap(),
-        );
+        )
+        .unwrap();
         let long_example =
-            SortedAdapters::new(parse_lines_file("../assets/days/day10_example_long.txt").unwrap());
+            SortedAdapters::new(parse_lines_file("../assets/days/day10_example_long.txt").unwrap())
+                .unwrap();
 
         // Given the adapters from the first example, the total number of arrangements that connect
         // the charging outlet to your device is 8.
@@ -146,4 +173,58 @@ mod tests {
         assert_eq!(8, combinations_short);
         assert_eq!(19208, combinations_long)
     }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Day10::parse("1\n4\nx7").unwrap_err();
+        assert!(matches!(error, AdapterError::ParseJolts(e) if e.line == 3));
+        assert!(matches!(Day10::parse("1\n5"), Err(AdapterError::NoChain)));
+    }
+
+    // Tries every subset of the adapters between the outlet and the device
+    fn count_chains_naive(adapters: &SortedAdapters) -> u64 {
+        let (outlet, rest) = adapters.adapters.split_first().unwrap();
+        let (device, between) = rest.split_last().unwrap();
+        (0..1_u32 << between.len())
+            .filter(|subset| {
+                let chosen = between
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| subset & 1 << i != 0)
+                    .map(|(_, jolts)| jolts);
+                std::iter::once(outlet)
+                    .chain(chosen)
+                    .chain(std::iter::once(device))
+                    .tuple_windows()
+                    .all(|(a, b)| b - a <= MAX_DIFF)
+            })
+            .count() as u64
+    }
+
+    proptest! {
+        // adapters are generated from the gaps between them, in random order
+        #[test]
+        fn test_combinations_match_enumeration(
+            adapters in prop::collection::vec(0..=MAX_DIFF + 1, 0..14)
+                .prop_map(|gaps| {
+                    let mut jolts = 0;
+                    gaps.iter().map(|gap| { jolts += gap; jolts }).collect::<Vec<_>>()
+                })
+                .prop_shuffle(),
+        ) {
+            // the outlet is part of the chain
+            let gaps_fit = std::iter::once(&0)
+                .chain(adapters.iter().sorted())
+                .tuple_windows()
+                .all(|(a, b)| (1..=MAX_DIFF).contains(&(b - a)));
+            let sorted = SortedAdapters::new(adapters.clone());
+            prop_assert_eq!(gaps_fit, sorted.is_ok());
+            if let Ok(sorted) = sorted {
+                prop_assert_eq!(
+                    count_chains_naive(&sorted),
+                    sorted.count_possible_valid_combinations()
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,287 @@
+use helpers::automaton::{Automaton, LineOfSight, Moore, Neighbourhood, Neighbours};
+use helpers::{Detailed, Grid, ParseGridError, Solution};
+use std::convert::TryFrom;
+use thiserror::Error;
+use std::fmt::{Display, Formatter, Write};
+use crate::Tile::{SeatTaken, Floor};
+use tracing::debug;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input<'a> = Grid<Tile>;
+    type Error = FerryError;
+    type Part1 = Detailed<usize>;
+    type Part2 = Detailed<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        parse_grid(input)
+    }
+
+    // Part 1
+    // Simulate your seating area by applying the seating rules repeatedly until no seats change
+    // state. How many seats end up occupied?
+    fn part1(grid: &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        let ferry = Ferry { grid: grid.clone() };
+        let (stable, rounds) = ferry.run_until_stable(Moore, 4);
+        Ok(Detailed::new(stable.count_seats_occupied()).with("iterations", rounds))
+    }
+
+    // Part 2
+    // Given the new visibility method and the rule change for occupied seats becoming empty, once
+    // equilibrium is reached, how many seats end up occupied?
+    fn part2(grid: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        let ferry = Ferry { grid: grid.clone() };
+        // only the first seat in each direction counts
+        let (stable, rounds) = ferry.run_until_stable(LineOfSight(is_seat), 5);
+        Ok(Detailed::new(stable.count_seats_occupied()).with("iterations", rounds))
+    }
+}
+
+#[derive(Clone,Debug, Eq, PartialEq)]
+struct Ferry {
+    grid: Grid<Tile>,
+}
+
+impl Ferry {
+    fn count_seats_occupied(&self) -> usize {
+        self.grid.iter().filter(|s| **s == SeatTaken).count()
+    }
+
+    // Empty seats without occupied seats around become occupied, occupied seats with at least
+    // `tolerance` occupied seats around become empty
+    fn seating<N: Neighbourhood<Grid<Tile>>>(
+        &self,
+        neighbourhood: N,
+        tolerance: usize,
+    ) -> Automaton<Grid<Tile>, N, impl FnMut(&Tile, Neighbours<Tile>) -> Tile> {
+        Automaton::new(self.grid.clone(), neighbourhood, move |tile, mut neighbours| match tile {
+            Tile::Floor => *tile,
+            Tile::SeatEmpty if !neighbours.any(|n| *n == SeatTaken) => Tile::SeatTaken,
+            Tile::SeatTaken if neighbours.filter(|n| **n == SeatTaken).count() >= tolerance => {
+                Tile::SeatEmpty
+            }
+            _ => *tile,
+        })
+    }
+
+    // Applies the seating rules until no seats change, returns the final ferry and the number of
+    // rounds which changed seats
+    fn run_until_stable<N: Neighbourhood<Grid<Tile>>>(
+        &self,
+        neighbourhood: N,
+        tolerance: usize,
+    ) -> (Ferry, usize) {
+        let mut seating = self.seating(neighbourhood, tolerance);
+        let rounds = seating.run_to_fixpoint();
+        debug!(rounds, tolerance, "stable");
+        (
+            Ferry {
+                grid: seating.into_state(),
+            },
+            rounds,
+        )
+    }
+}
+
+fn is_seat(tile: &Tile) -> bool {
+    *tile != Floor
+}
+
+// TODO Refactor to Grid<Option<Seat>> with Seat::Empty and Seat::Occupied
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Tile {
+    Floor,
+    SeatEmpty,
+    SeatTaken,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Tile::Floor => '.',
+            Tile::SeatEmpty => 'L',
+            Tile::SeatTaken => '#',
+        };
+        f.write_char(c)
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = UnknownTile;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Tile::SeatTaken),
+            'L' => Ok(Tile::SeatEmpty),
+            // replace with option?
+            '.' => Ok(Tile::Floor),
+            _ => Err(UnknownTile(value)),
+        }
+    }
+}
+
+fn parse_grid(s: &str) -> Result<Grid<Tile>, FerryError> {
+    let grid: Grid<Tile> = s.parse()?;
+    debug!(columns = grid.num_columns(), rows = grid.num_rows(), "seat layout");
+    Ok(grid)
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum FerryError {
+    #[error("invalid seat layout, {0}")]
+    Parse(#[from] ParseGridError<UnknownTile>),
+}
+
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+#[error("unknown tile {0}, only '#', '.' and 'L' are allowed")]
+pub struct UnknownTile(char);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use helpers::read_file;
+    use crate::Tile::{Floor, SeatEmpty, SeatTaken};
+
+    // TODO move to helpers
+    #[test]
+    fn test_parse_grid() {
+        let input = "LLL\n###\n...";
+        let expected = vec![
+            SeatEmpty, SeatEmpty, SeatEmpty, SeatTaken, SeatTaken, SeatTaken, Floor, Floor, Floor,
+        ];
+
+        let grid = parse_grid(input).unwrap();
+
+        assert_eq!(grid.items(), expected)
+    }
+
+    // TODO move to helpers
+    #[test]
+    fn test_grid_get() {
+        let input = "LLL\n###\n...";
+        let grid = parse_grid(input).unwrap();
+
+        assert_eq!(Some(&SeatEmpty), grid.get(0, 0));
+        assert_eq!(Some(&SeatEmpty), grid.get(1, 0));
+        assert_eq!(Some(&SeatEmpty), grid.get(2, 0));
+
+        assert_eq!(Some(&SeatTaken), grid.get(0, 1));
+        assert_eq!(Some(&SeatTaken), grid.get(1, 1));
+        assert_eq!(Some(&SeatTaken), grid.get(2, 1));
+
+        assert_eq!(Some(&Floor), grid.get(0, 2));
+        assert_eq!(Some(&Floor), grid.get(1, 2));
+        assert_eq!(Some(&Floor), grid.get(2, 2));
+    }
+
+    // TODO move to helpers
+    #[test]
+    fn test_grid_items() {
+        let input = "LLL\n###\n...";
+        let grid = parse_grid(input).unwrap();
+
+        let items: Vec<_> = grid.items_iter().map(|(_,tile)|*tile).collect();
+        let new_grid = Grid::with_items(items, grid.num_columns()).unwrap();
+
+        assert_eq!(new_grid.items(), grid.items())
+    }
+
+    #[test]
+    fn test_ferry_get() {
+        let input = "LLL\n###\n...";
+        let ferry = Ferry {
+            grid: parse_grid(input).unwrap(),
+        };
+
+        assert_eq!(Some(SeatEmpty), ferry.grid.get(0, 0).copied());
+        assert_eq!(Some(SeatEmpty), ferry.grid.get(1, 0).copied());
+        assert_eq!(Some(SeatEmpty), ferry.grid.get(2, 0).copied());
+
+        assert_eq!(Some(SeatTaken), ferry.grid.get(0, 1).copied());
+        assert_eq!(Some(SeatTaken), ferry.grid.get(1, 1).copied());
+        assert_eq!(Some(SeatTaken), ferry.grid.get(2, 1).copied());
+
+        assert_eq!(Some(Floor), ferry.grid.get(0, 2).copied());
+        assert_eq!(Some(Floor), ferry.grid.get(1, 2).copied());
+        assert_eq!(Some(Floor), ferry.grid.get(2, 2).copied());
+    }
+
+    fn neighbours<N: Neighbourhood<Grid<Tile>>>(
+        ferry: &Ferry,
+        neighbourhood: N,
+        col: usize,
+        row: usize,
+    ) -> Vec<Tile> {
+        neighbourhood
+            .neighbours(&ferry.grid, (col, row))
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn test_ferry_get_neighbours() {
+        let input = "LLL\n###\n...";
+
+        let ferry = Ferry {
+            grid: parse_grid(input).unwrap(),
+        };
+
+        let neighbours_origin = neighbours(&ferry, Moore, 0, 0);
+        let neighbours_middle = neighbours(&ferry, Moore, 1, 1);
+        let neighbours_bottom_right = neighbours(&ferry, Moore, 2, 2);
+
+        assert_eq!(&neighbours_origin, &vec![SeatEmpty, SeatTaken, SeatTaken]);
+        assert_eq!(
+            &neighbours_middle,
+            &vec![SeatEmpty, SeatEmpty, SeatEmpty, SeatTaken, SeatTaken, Floor, Floor, Floor]
+        );
+        assert_eq!(&neighbours_bottom_right, &vec![SeatTaken, SeatTaken, Floor]);
+
+    }
+    
+    #[test]
+    fn test_part1_example() {
+        let input = read_file("../assets/days/day11_example.txt").unwrap();
+        let grid = parse_grid(&input).unwrap();
+
+        let ferry = Ferry{ grid };
+
+        // all seats are empty
+        assert!(ferry.grid.items_iter().all(|(_,t)|*t==SeatEmpty||*t==Floor));
+        // all seats are now filled
+        let mut seating = ferry.seating(Moore, 4);
+        seating.step();
+        assert!(seating.state().iter().all(|t|*t==SeatTaken||*t==Floor));
+
+        // after 5 rounds there are no more changes
+        let (stable, rounds) = ferry.run_until_stable(Moore, 4);
+        assert_eq!(5, rounds);
+        assert_eq!(37, stable.count_seats_occupied())
+    }
+
+    // Now, instead of considering just the eight immediately adjacent seats, consider the first
+    // seat in each of those eight directions. For example, the empty seat below would see
+    // eight occupied seats:
+    // .......#.
+    // ...#.....
+    // .#.......
+    // .........
+    // ..#L....#
+    // ....#....
+    // .........
+    // #........
+    // ...#.....
+    #[test]
+    fn test_part2_visibility_example() {
+        let input = read_file("../assets/days/day11_example_part2.txt").unwrap();
+        let grid = parse_grid(&input).unwrap();
+
+        let ferry = Ferry{ grid };
+
+        let visible = neighbours(&ferry, LineOfSight(is_seat), 3, 4);
+        assert_eq!(visible.iter().filter(|t|**t==SeatTaken).count(), 8 );
+    }
+
+}
@@ -1,42 +1,48 @@
-use std::error::Error;
+use helpers::{parse_lines, ParseError, Solution};
 use std::num::ParseIntError;
 use std::ops::Neg;
 use std::str::FromStr;
 use thiserror::Error;
+use tracing::{debug, trace};
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let instructions: Vec<Instruction> = helpers::parse_lines_file("./assets/days/day12.txt")?;
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input<'a> = Vec<Instruction>;
+    type Error = ParseError<InstructionError>;
+    type Part1 = Unit;
+    type Part2 = Unit;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        let instructions = parse_lines(input, Instruction::from_str)?;
+        debug!(instructions = instructions.len(), "navigation instructions");
+        Ok(instructions)
+    }
 
     // Part 1
     // Figure out where the navigation instructions lead. What is the Manhattan distance between
     // that location and the ship's starting position?
-    let mut ferry = Ferry::new();
-    for i in &instructions {
-        ferry.act(*i)
+    fn part1(instructions: &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        let mut ferry = Ferry::new();
+        for i in instructions {
+            ferry.act(*i);
+            trace!(instruction = ?i, ferry.x, ferry.y, direction = ?ferry.direction, "act");
+        }
+        Ok(ferry.manhattan_distance())
     }
-    let (x, y) = ferry.relative_position();
-    println!(
-        "Part1: Relative position {}, {}, Manhattan Distance: {}",
-        x,
-        y,
-        x.abs() + y.abs()
-    );
 
     // Part 2
     // Figure out where the navigation instructions actually lead. What is the Manhattan distance
     // between that location and the ship's starting position?
-    let mut ferry = Ferry::new();
-    for i in &instructions {
-        ferry.act_with_waypoint(*i)
+    fn part2(instructions: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        let mut ferry = Ferry::new();
+        for i in instructions {
+            ferry.act_with_waypoint(*i);
+            trace!(instruction = ?i, ferry.x, ferry.y, waypoint = ?ferry.waypoint, "act");
+        }
+        Ok(ferry.manhattan_distance())
     }
-    let (x, y) = ferry.relative_position();
-    println!(
-        "Part2: Relative position {}, {}, Manhattan Distance: {}",
-        x,
-        y,
-        x.abs() + y.abs()
-    );
-    Ok(())
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -62,6 +68,11 @@ impl Ferry {
         (self.x, self.y)
     }
 
+    pub fn manhattan_distance(&self) -> Unit {
+        let (x, y) = self.relative_position();
+        x.abs() + y.abs()
+    }
+
     // Part 1
     pub fn act(&mut self, instruction: Instruction) {
         match instruction.action {
@@ -185,7 +196,7 @@ impl Default for Waypoint {
 type Unit = i128;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
-struct Instruction {
+pub struct Instruction {
     action: Action,
     value: Unit,
 }
@@ -214,9 +225,8 @@ impl FromStr for Instruction {
     type Err = InstructionError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Some(action) = s.chars().next() {
-            let value = s[action.len_utf8()..].parse()?;
-            let action = match action {
+        if let Some(letter) = s.chars().next() {
+            let action = match letter {
                 'N' => Ok(Action::Direction(Direction::North)),
                 'E' => Ok(Action::Direction(Direction::East)),
                 'S' => Ok(Action::Direction(Direction::South)),
@@ -224,8 +234,9 @@ impl FromStr for Instruction {
                 'L' => Ok(Action::Turn(Turn::Left)),
                 'R' => Ok(Action::Turn(Turn::Right)),
                 'F' => Ok(Action::Forward),
-                _ => Err(InstructionError::Unknown(action)),
+                _ => Err(InstructionError::Unknown(letter)),
             }?;
+            let value = s[letter.len_utf8()..].parse()?;
             Ok(Instruction { action, value })
         } else {
             Err(InstructionError::Empty)
@@ -234,10 +245,10 @@ impl FromStr for Instruction {
 }
 
 #[derive(Debug, Clone, Error)]
-enum InstructionError {
-    #[error("could not parse instruction")]
+pub enum InstructionError {
+    #[error("could not parse instruction value, {0}")]
     ParseValue(#[from] ParseIntError),
-    #[error("unrecognized instruction")]
+    #[error("unknown action {0:?}, expected one of N, S, E, W, L, R or F")]
     Unknown(char),
     #[error("empty instruction")]
     Empty,
@@ -281,4 +292,22 @@ F11";
         // position is 17 + 8 = 25.
         assert_eq!(ship.x.abs() + ship.y.abs(), 286)
     }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Day12::parse("F10\nX3").unwrap_err();
+        assert_eq!((2, "X3"), (error.line, error.snippet.as_str()));
+        assert!(matches!(error.error, InstructionError::Unknown('X')));
+        // the action is reported even if the value is broken as well
+        let error = Day12::parse("Xabc").unwrap_err();
+        assert!(matches!(error.error, InstructionError::Unknown('X')));
+        assert_eq!(
+            "unknown action 'X', expected one of N, S, E, W, L, R or F",
+            error.error.to_string()
+        );
+        let error = Day12::parse("Fabc").unwrap_err();
+        assert!(matches!(error.error, InstructionError::ParseValue(_)));
+        let error = Day12::parse("F10\n\nN3").unwrap_err();
+        assert!(matches!(error.error, InstructionError::Empty));
+    }
 }
@@ -0,0 +1,176 @@
+use helpers::{ParseError, Solution};
+use std::num::ParseIntError;
+use thiserror::Error;
+use tracing::{debug, trace};
+
+type Unit = isize;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input<'a> = (Unit, Vec<ScheduledBus>);
+    type Error = ScheduleError;
+    type Part1 = Unit;
+    type Part2 = Unit;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        parse_timestamp_and_schedules(input)
+    }
+
+    // Part 1
+    // What is the ID of the earliest bus you can take to the airport multiplied by the number of
+    // minutes you'll need to wait for that bus?
+    fn part1((start_ts, scheduled_busses): &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        if scheduled_busses.is_empty() {
+            return Err(ScheduleError::MissingSchedules);
+        }
+        let (first_ts, bus) = (*start_ts..)
+            .filter_map(|timestamp| {
+                scheduled_busses
+                    .iter()
+                    .find(|bus| timestamp % bus.interval == 0)
+                    .map(|schedule| (timestamp, schedule))
+            })
+            .next()
+            .unwrap();
+        debug!(timestamp = first_ts, bus = bus.interval, "earliest bus");
+
+        Ok((first_ts - start_ts) * bus.interval)
+    }
+
+    // Part 2
+    // What is the earliest timestamp such that all of the listed bus IDs depart at offsets
+    // matching their positions in the list?
+    fn part2((_, scheduled_busses): &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        let mut step = 1;
+        let mut timestamp = 0;
+        // we sieve through the timestamps by finding a solution for each bus
+        // when each bus is solved, the condition is reached
+        // combining the solutions is possible by increasing the step size, stepping in multiples
+        // of the bus intervals, once a solution is found
+        for bus in scheduled_busses {
+            // step in the future until conditions are satisfied also for the current bus
+            while (timestamp + bus.departure_offset) % bus.interval != 0 {
+                timestamp += step;
+            }
+            trace!(bus = bus.interval, timestamp, "solved");
+
+            step *= bus.interval;
+        }
+
+        Ok(timestamp)
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct ScheduledBus {
+    departure_offset: Unit,
+    interval: Unit,
+}
+
+fn parse_timestamp_and_schedules(s: &str) -> Result<(Unit, Vec<ScheduledBus>), ScheduleError> {
+    let mut lines = s.lines();
+
+    let timestamp = lines
+        .next()
+        .ok_or_else(|| ParseError::end(s, NotesError::MissingTimestamp))?;
+    let timestamp = timestamp
+        .parse::<Unit>()
+        .map_err(|e| ParseError::at(s, timestamp, e.into()))?;
+
+    let schedule = lines
+        .next()
+        .ok_or_else(|| ParseError::end(s, NotesError::MissingSchedules))?;
+
+    let busses: Result<Vec<_>, _> = schedule
+        .split(',')
+        .enumerate()
+        .filter(|s| s.1 != "x")
+        .map(|(idx, interval)| {
+            parse_interval(interval)
+                .map(|interval| ScheduledBus {
+                    departure_offset: idx as isize,
+                    interval,
+                })
+                .map_err(|e| ParseError::at(s, interval, e))
+        })
+        .collect();
+    let busses = busses?;
+
+    debug!(timestamp, busses = busses.len(), "notes");
+    Ok((timestamp, busses))
+}
+
+fn parse_interval(s: &str) -> Result<Unit, NotesError> {
+    match s.parse()? {
+        interval if interval > 0 => Ok(interval),
+        _ => Err(NotesError::InvalidInterval),
+    }
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum ScheduleError {
+    #[error("could not parse notes, {0}")]
+    Parse(#[from] ParseError<NotesError>),
+    #[error("schedules not found")]
+    MissingSchedules,
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum NotesError {
+    #[error("timestamp not found")]
+    MissingTimestamp,
+    #[error("schedules not found")]
+    MissingSchedules,
+    #[error("could not parse number, {0}")]
+    ParseNumber(#[from] ParseIntError),
+    #[error("bus intervals must be positive")]
+    InvalidInterval,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // Tries every timestamp up to the product of the intervals, after which departures repeat
+    fn earliest_timestamp_naive(busses: &[ScheduledBus]) -> Option<Unit> {
+        let period: Unit = busses.iter().map(|bus| bus.interval).product();
+        (0..period).find(|timestamp| {
+            busses
+                .iter()
+                .all(|bus| (timestamp + bus.departure_offset) % bus.interval == 0)
+        })
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let notes = parse_timestamp_and_schedules("939\n7,13,x,x,59,x,31,19\n").unwrap();
+        assert_eq!(1068781, Day13::part2(&notes).unwrap());
+    }
+
+    proptest! {
+        // The sieve relies on pairwise coprime intervals, like the primes of the puzzle input.
+        // Busses are shuffled and spread out between out of service positions.
+        #[test]
+        fn test_sieve_matches_search(
+            intervals in prop::sample::subsequence(vec![2, 3, 5, 7, 11, 13], 1..=6).prop_shuffle(),
+            gaps in prop::collection::vec(0..3_usize, 6),
+        ) {
+            let schedule: Vec<_> = intervals
+                .iter()
+                .zip(&gaps)
+                .flat_map(|(interval, &gap)| {
+                    std::iter::repeat_n("x".to_string(), gap).chain(Some(interval.to_string()))
+                })
+                .collect();
+            let input = format!("0\n{}", schedule.join(","));
+            let notes = parse_timestamp_and_schedules(&input).unwrap();
+            prop_assert_eq!(
+                earliest_timestamp_naive(&notes.1),
+                Some(Day13::part2(&notes).unwrap())
+            );
+        }
+    }
+}
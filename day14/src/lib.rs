@@ -0,0 +1,258 @@
+use helpers::parser::{
+    alt, delimited, integer, lines, literal, map, preceded, separated_pair, spanned, take_while1,
+    verify, PResult, SyntaxError,
+};
+use helpers::{parser, ParseError, Solution};
+use std::collections::HashMap;
+use thiserror::Error;
+use tracing::debug;
+
+// Addresses and values are 36 bit unsigned integers
+const BITS: u32 = 36;
+const MAX_VALUE: u64 = (1 << BITS) - 1;
+// A write of the version 2 decoder goes to 2^floating bits addresses. Masks of the puzzle inputs
+// float at most 9 bits, more than this limit would take ages.
+const MAX_FLOATING_BITS: u32 = 16;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input<'a> = Vec<Instruction>;
+    type Error = DockingError;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        parse_program(input)
+    }
+
+    // Part 1
+    // Execute the initialization program. What is the sum of all values left in memory after it
+    // completes?
+    fn part1(program: &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        let mut memory = Memory::default();
+        let mut mask = Mask::default();
+        for instruction in program {
+            match *instruction {
+                Instruction::SetMask(m) => mask = m,
+                Instruction::Write { address, value } => memory.write(address, mask.apply(value)),
+            }
+        }
+        Ok(memory.sum())
+    }
+
+    // Part 2
+    // Execute the initialization program using an emulator for a version 2 decoder chip. What is
+    // the sum of all values left in memory after it completes?
+    fn part2(program: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        let mut memory = Memory::default();
+        let mut mask = Mask::default();
+        for instruction in program {
+            match *instruction {
+                Instruction::SetMask(m) => {
+                    let floating = m.floating.count_ones();
+                    if floating > MAX_FLOATING_BITS {
+                        return Err(DockingError::TooManyFloatingBits(floating));
+                    }
+                    mask = m
+                }
+                Instruction::Write { address, value } => {
+                    for address in mask.decode(address) {
+                        memory.write(address, value);
+                    }
+                }
+            }
+        }
+        Ok(memory.sum())
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Instruction {
+    SetMask(Mask),
+    Write { address: u64, value: u64 },
+}
+
+// The bits of a mask like `X1001X`, each bit is set in exactly one of the fields
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Mask {
+    ones: u64,
+    zeros: u64,
+    floating: u64,
+}
+
+impl Mask {
+    // Version 1: 0 or 1 overwrite the bit of the value, X leaves it unchanged
+    fn apply(&self, value: u64) -> u64 {
+        (value | self.ones) & !self.zeros
+    }
+
+    // Version 2: 1 overwrites the bit of the address, 0 leaves it unchanged and X floats, it takes
+    // both values. Returns all addresses the mask decodes to.
+    fn decode(&self, address: u64) -> impl Iterator<Item = u64> {
+        let base = (address | self.ones) & !self.floating;
+        let floating = self.floating;
+        // every subset of the floating bits, counting up within the floating bits only
+        let mut subset = Some(0_u64);
+        std::iter::from_fn(move || {
+            let current = subset?;
+            let next = current.wrapping_sub(floating) & floating;
+            subset = if next == 0 { None } else { Some(next) };
+            Some(base | current)
+        })
+    }
+}
+
+// Only written addresses are stored, the 36 bit address space is far too large to allocate
+#[derive(Debug, Default)]
+struct Memory {
+    cells: HashMap<u64, u64>,
+}
+
+impl Memory {
+    fn write(&mut self, address: u64, value: u64) {
+        self.cells.insert(address, value);
+    }
+
+    fn sum(&self) -> u64 {
+        debug!(cells = self.cells.len(), "memory");
+        self.cells.values().sum()
+    }
+}
+
+fn parse_program(input: &str) -> Result<Vec<Instruction>, DockingError> {
+    let parsed =
+        parser::parse(input, lines(spanned(instruction))).map_err(|e| e.map(ProgramError::from))?;
+    // the mask is unknown until it is set
+    if let Some((Instruction::Write { .. }, line)) = parsed.first() {
+        return Err(ParseError::at(input, line, ProgramError::WriteBeforeMask).into());
+    }
+    debug!(instructions = parsed.len(), "initialization program");
+    Ok(parsed
+        .into_iter()
+        .map(|(instruction, _)| instruction)
+        .collect())
+}
+
+// mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+// mem[8] = 11
+fn instruction(input: &str) -> PResult<'_, Instruction> {
+    let set_mask = map(preceded(literal("mask = "), mask), Instruction::SetMask);
+    let address = delimited(literal("mem["), number, literal("]"));
+    let write = map(
+        separated_pair(address, literal(" = "), number),
+        |(address, value)| Instruction::Write { address, value },
+    );
+    alt(set_mask, write)(input)
+}
+
+fn mask(input: &str) -> PResult<'_, Mask> {
+    let bits = take_while1(|c| matches!(c, '0' | '1' | 'X'), "a mask of 0, 1 and X");
+    let bits = verify(
+        bits,
+        |bits| bits.len() == BITS as usize,
+        "a mask of 36 bits",
+    );
+    map(bits, |bits| {
+        // the bits with the symbol set, the first one is the most significant
+        let field = |symbol| {
+            bits.bytes()
+                .fold(0, |field, bit| field << 1 | u64::from(bit == symbol))
+        };
+        Mask {
+            ones: field(b'1'),
+            zeros: field(b'0'),
+            floating: field(b'X'),
+        }
+    })(input)
+}
+
+fn number(input: &str) -> PResult<'_, u64> {
+    verify(integer(), |n| *n <= MAX_VALUE, "a 36 bit number")(input)
+}
+
+#[derive(Error, Clone, Debug)]
+pub enum DockingError {
+    #[error("could not parse program, {0}")]
+    Parse(#[from] ParseError<ProgramError>),
+    #[error(
+        "a mask floats {0} bits, the decoder supports at most {}",
+        MAX_FLOATING_BITS
+    )]
+    TooManyFloatingBits(u32),
+}
+
+#[derive(Error, Clone, Debug, Eq, PartialEq)]
+pub enum ProgramError {
+    #[error("{0}")]
+    Syntax(#[from] SyntaxError),
+    #[error("memory is written before the first mask is set")]
+    WriteBeforeMask,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use helpers::read_file;
+
+    #[test]
+    fn test_example_part1() {
+        let input = read_file("../assets/days/day14_example.txt").unwrap();
+        let program = Day14::parse(&input).unwrap();
+        assert_eq!(165, Day14::part1(&program).unwrap());
+
+        // the mask of the first example floats 34 bits
+        assert!(matches!(
+            Day14::part2(&program),
+            Err(DockingError::TooManyFloatingBits(34))
+        ));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let input = read_file("../assets/days/day14_example_part2.txt").unwrap();
+        let program = Day14::parse(&input).unwrap();
+        assert_eq!(208, Day14::part2(&program).unwrap());
+    }
+
+    #[test]
+    fn test_mask() {
+        let (v1, _) = mask("XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X").unwrap();
+        assert_eq!(
+            vec![73, 101, 64],
+            vec![v1.apply(11), v1.apply(101), v1.apply(0)]
+        );
+
+        let (v2, _) = mask("000000000000000000000000000000X1001X").unwrap();
+        assert_eq!(vec![26, 27, 58, 59], v2.decode(42).collect::<Vec<_>>());
+        let (v2, _) = mask("000000000000000000000000000000000000").unwrap();
+        assert_eq!(vec![42], v2.decode(42).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| match Day14::parse(input) {
+            Err(DockingError::Parse(e)) => (e.line, e.column, e.error),
+            _ => panic!("expected a parse error"),
+        };
+        let mask = "mask = 000000000000000000000000000000X1001X\n";
+        let expected = |expected| ProgramError::Syntax(SyntaxError::Expected(expected));
+        assert!(matches!(
+            error("mask = 01X\nmem[1] = 2"),
+            (1, 8, e) if e == expected("a mask of 36 bits")
+        ));
+        assert!(matches!(
+            error(&(mask.to_string() + "mem[68719476736] = 1")),
+            (2, 5, e) if e == expected("a 36 bit number")
+        ));
+        assert!(matches!(
+            error(&(mask.to_string() + "mem[1]=1")),
+            (2, 7, ProgramError::Syntax(SyntaxError::Literal(" = ")))
+        ));
+        assert!(matches!(
+            error("mem[1] = 1"),
+            (1, 1, ProgramError::WriteBeforeMask)
+        ));
+    }
+}
@@ -1,33 +1,45 @@
-use helpers::read_file;
+use helpers::{ParseError, Solution};
 use std::collections::HashMap;
-use std::error::Error;
+use std::convert::TryFrom;
+use std::num::ParseIntError;
 use std::str::FromStr;
+use thiserror::Error;
+use tracing::debug;
 
 type Unit = usize;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = read_file("./assets/days/day15.txt").expect("error reading inputfile");
-    let starting_numbers: Result<Vec<_>, _> = input.split(',').map(Unit::from_str).collect();
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input<'a> = Vec<Unit>;
+    type Error = MemoryError;
+    type Part1 = Unit;
+    type Part2 = Unit;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        let starting_numbers: Result<Vec<_>, _> = input
+            .trim()
+            .split(',')
+            .map(|n| Unit::from_str(n).map_err(|e| ParseError::at(input, n, e)))
+            .collect();
+        let starting_numbers = starting_numbers?;
+        debug!(numbers = ?starting_numbers, "starting numbers");
+        Ok(starting_numbers)
+    }
 
     // Part 1
     // Given your starting numbers, what will be the 2020th number spoken?
-    let mut memory = Memory::with_starting_numbers(&starting_numbers?);
-    let part_1_limit = 2020;
-    println!(
-        "Number at {} is: {:?}",
-        part_1_limit,
-        memory.run_until(part_1_limit)
-    );
+    fn part1(starting_numbers: &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        let mut memory = Memory::with_starting_numbers(starting_numbers);
+        memory.run_until(2020).ok_or(MemoryError::NotEnoughTurns)
+    }
 
     // Part 2
     // Given your starting numbers, what will be the 30000000th number spoken?
-    let part_2_limit = 30_000_000;
-    println!(
-        "Number at {} is: {:?}",
-        part_1_limit,
-        memory.run_until(part_2_limit)
-    );
-    Ok(())
+    fn part2(starting_numbers: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        spoken_at(starting_numbers, 30_000_000).ok_or(MemoryError::NotEnoughTurns)
+    }
 }
 
 // They're playing a memory game and are ever so excited to explain the rules!
@@ -81,9 +93,49 @@ impl Memory {
     }
 }
 
+// The same game as Memory, but the turns are kept in a vector indexed by the number. Twice as fast
+// as hashing for millions of turns, the vector takes about as much memory as the map.
+fn spoken_at(starting_numbers: &[Unit], last: usize) -> Option<Unit> {
+    let (&last_starting, before) = starting_numbers.split_last()?;
+    let count = starting_numbers.len();
+    if last <= count || u32::try_from(last).is_err() {
+        return None;
+    }
+    // Turn the number was spoken at last, 0 if it was never spoken. Every number spoken after the
+    // starting numbers is a difference of turns and smaller than the last turn, so larger starting
+    // numbers are never looked up.
+    let mut turns = vec![0_u32; last];
+    for (i, &n) in starting_numbers.iter().enumerate() {
+        if let Some(turn) = turns.get_mut(n) {
+            *turn = i as u32 + 1;
+        }
+    }
+    let mut number = match before.iter().rposition(|&n| n == last_starting) {
+        Some(i) => count - (i + 1),
+        None => 0,
+    };
+    for turn in count as u32 + 1..last as u32 {
+        number = match std::mem::replace(&mut turns[number], turn) {
+            0 => 0,
+            spoken => (turn - spoken) as Unit,
+        };
+    }
+    debug!(turn = last, distinct = turns.iter().filter(|&&t| t != 0).count(), "spoken");
+    Some(number)
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum MemoryError {
+    #[error("the requested turn is among the starting numbers")]
+    NotEnoughTurns,
+    #[error("could not parse starting number, {0}")]
+    ParseNumber(#[from] ParseError<ParseIntError>),
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn test_example_day1() {
@@ -140,4 +192,27 @@ mod tests {
             assert_eq!(*expected, memory.run_until(2020).unwrap());
         }
     }
+
+    #[test]
+    fn test_spoken_at() {
+        assert_eq!(Some(436), spoken_at(&[0, 3, 6], 2020));
+        assert_eq!(Some(1836), spoken_at(&[3, 1, 2], 2020));
+        assert_eq!(None, spoken_at(&[0, 3, 6], 3));
+        assert_eq!(None, spoken_at(&[], 2020));
+    }
+
+    proptest! {
+        // the vector is checked against the hash map, repeated and large starting numbers included
+        #[test]
+        fn test_spoken_at_matches_memory(
+            starting_numbers in prop::collection::vec(
+                prop_oneof![0..10_usize, Just(1 << 40)],
+                1..7
+            ),
+            last in 1..3000_usize,
+        ) {
+            let mut memory = Memory::with_starting_numbers(&starting_numbers);
+            prop_assert_eq!(memory.run_until(last), spoken_at(&starting_numbers, last));
+        }
+    }
 }
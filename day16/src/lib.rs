@@ -0,0 +1,387 @@
+use helpers::parser::{
+    integer, lines, literal, map, pair, preceded, separated, separated_pair, spanned, take_until,
+    terminated, PResult, SyntaxError,
+};
+#[cfg(test)]
+use helpers::read_file;
+use helpers::{matching, parser, ParseError, Solution};
+#[cfg(test)]
+use std::collections::BTreeMap;
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+use thiserror::Error;
+use tracing::{debug, trace};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input<'a> = TicketTranslation<'a>;
+    type Error = TicketError;
+    type Part1 = Unit;
+    type Part2 = Unit;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        parse_ticket_translation(input)
+    }
+
+    // you can identify invalid nearby tickets by considering only whether tickets contain values
+    // that are not valid for any field
+    // Adding together all of the invalid values produces your ticket scanning error rate
+    fn part1(ticket_translation: &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        Ok(ticket_translation.scan_error_rate())
+    }
+
+    // Once you work out which field is which, look for the six fields on your ticket that start
+    // with the word departure. What do you get if you multiply those six values together?
+    fn part2(ticket_translation: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        let named_values = ticket_translation.valid_ticket_named_values()?;
+        let product: Unit = named_values
+            .iter()
+            .filter(|(n, _)| n.starts_with("departure"))
+            .map(|c| c.1)
+            .product();
+        Ok(product)
+    }
+}
+
+type Unit = usize;
+
+pub struct TicketTranslation<'a> {
+    constraints: Constraints<'a>,
+    ticket: Ticket,
+    other_tickets: Vec<Ticket>,
+    ticket_values: Unit,
+}
+
+impl<'a> TicketTranslation<'a> {
+    pub fn scan_error_rate(&self) -> Unit {
+        self.other_tickets
+            .iter()
+            .map(|ticket| self.constraints.scan_error_rate(ticket))
+            .sum()
+    }
+
+    pub fn valid_tickets(&self) -> impl Iterator<Item = &Ticket> + '_ {
+        self.other_tickets
+            .iter()
+            .filter(move |t| self.constraints.is_valid(t))
+    }
+
+    fn get_valid_field_values(&self, index: usize) -> impl Iterator<Item = Unit> + '_ {
+        self.valid_tickets().map(move |ticket| ticket.values[index])
+    }
+
+    pub fn valid_ticket_named_values(&self) -> Result<HashMap<&str, Unit>, TicketError> {
+        let mappings = self.get_mappings()?;
+        Ok(self.ticket.named_values(&mappings))
+    }
+
+    pub fn candidate_mappings(&self) -> Vec<HashSet<&str>> {
+        let mut candidates = vec![HashSet::new(); self.ticket_values];
+
+        // find candidates
+        for (field, candidate) in candidates.iter_mut().enumerate() {
+            let fv: Vec<_> = self.get_valid_field_values(field).collect();
+            for (name, constraint) in &self.constraints.constraints {
+                if fv.iter().all(|f| constraint.matches_any_range(*f)) {
+                    candidate.insert(*name);
+                }
+            }
+            trace!(field, candidates = ?candidate, "candidates");
+        }
+        candidates
+    }
+
+    pub fn get_mappings(&self) -> Result<HashMap<&str, usize>, TicketError> {
+        let candidates = self.candidate_mappings().into_iter().enumerate().collect();
+
+        // every column is one of the fields, there must be exactly one way to assign them
+        let mut matchings = matching::matchings(&candidates);
+        let mapping = matchings.next().ok_or(TicketError::NoFieldMapping)?;
+        if let Some(other) = matchings.next() {
+            let column = mapping
+                .iter()
+                .find(|(column, name)| other.get(column) != Some(name))
+                .map_or(0, |(column, _)| *column);
+            return Err(TicketError::AmbiguousFieldMapping(column));
+        }
+        debug!(?mapping, "fields");
+
+        Ok(mapping
+            .into_iter()
+            .map(|(column, name)| (name, column))
+            .collect())
+    }
+}
+
+pub fn parse_ticket_translation(input: &str) -> Result<TicketTranslation<'_>, TicketError> {
+    let (constraints, (ticket, other_tickets)) =
+        parser::parse(input, notes).map_err(|e| e.map(FormatError::from))?;
+
+    // sanity check, every ticket has as many values as yours
+    let length = ticket.values.len();
+
+    if let Some((_, span)) = other_tickets.iter().find(|(t, _)| t.values.len() != length) {
+        return Err(ParseError::at(input, span, FormatError::IrregularTicketValues).into());
+    }
+    debug!(
+        rules = constraints.len(),
+        nearby = other_tickets.len(),
+        values = length,
+        "notes"
+    );
+
+    Ok(TicketTranslation {
+        constraints: Constraints {
+            constraints: constraints.into_iter().collect(),
+        },
+        ticket,
+        other_tickets: other_tickets.into_iter().map(|(t, _)| t).collect(),
+        ticket_values: length,
+    })
+}
+
+// Rules, your ticket and nearby tickets, the nearby tickets come with their line
+type Notes<'a> = (Vec<(&'a str, Constraint)>, (Ticket, Vec<(Ticket, &'a str)>));
+
+fn notes(input: &str) -> PResult<'_, Notes<'_>> {
+    let constraints = terminated(lines(constraint), literal("\n\n"));
+    let own = preceded(literal("your ticket:\n"), ticket);
+    let nearby = preceded(literal("\n\nnearby tickets:\n"), lines(spanned(ticket)));
+    pair(constraints, pair(own, nearby))(input)
+}
+
+#[derive(Debug, Clone)]
+struct Constraints<'a> {
+    constraints: HashMap<&'a str, Constraint>,
+}
+
+impl<'a> Constraints<'a> {
+    // Start by determining which tickets are completely invalid; these are tickets that contain
+    // values which aren't valid for any field.
+    // It doesn't matter which position corresponds to which field; you can identify invalid nearby
+    // tickets by considering only whether tickets contain values that are not valid for any field.
+    // In this example, the values on the first nearby ticket are all valid for at least one field.
+    // This is not true of the other three nearby tickets: the values 4, 55, and 12 are are not
+    // valid for any field. Adding together all of the invalid values produces your ticket scanning
+    // error rate: 4 + 55 + 12 = 71.
+    fn scan_error_rate(&self, ticket: &Ticket) -> Unit {
+        ticket
+            .values
+            .iter()
+            .filter(|value| !self.matches_any(**value))
+            .sum()
+    }
+
+    fn is_valid(&self, ticket: &Ticket) -> bool {
+        ticket.values.iter().all(|value| self.matches_any(*value))
+    }
+
+    fn matches_any(&self, value: Unit) -> bool {
+        self.constraints
+            .iter()
+            .any(|(_, c)| c.matches_any_range(value))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Constraint {
+    ranges: Vec<RangeInclusive<Unit>>,
+}
+
+impl Constraint {
+    fn matches_any_range(&self, value: Unit) -> bool {
+        self.ranges.iter().any(|range| range.contains(&value))
+    }
+}
+
+// class: 1-3 or 5-7
+// row: 6-11 or 33-44
+// seat: 13-40 or 45-50
+fn constraint(input: &str) -> PResult<'_, (&str, Constraint)> {
+    let ranges = map(separated(range, literal(" or ")), |ranges| Constraint {
+        ranges,
+    });
+    separated_pair(take_until(": "), literal(": "), ranges)(input)
+}
+
+#[derive(Clone, Debug)]
+pub struct Ticket {
+    values: Vec<Unit>,
+}
+
+// 7,1,14
+fn ticket(input: &str) -> PResult<'_, Ticket> {
+    map(separated(integer(), literal(",")), |values| Ticket {
+        values,
+    })(input)
+}
+
+impl Ticket {
+    fn named_values<'a>(&'a self, mapping: &HashMap<&'a str, usize>) -> HashMap<&'a str, usize> {
+        mapping
+            .iter()
+            .map(|(name, idx)| (*name, self.values[*idx]))
+            .collect()
+    }
+}
+
+// format is [0-9]+-[0-9]+ e.g. 0-100
+fn range(input: &str) -> PResult<'_, RangeInclusive<Unit>> {
+    let min_max = separated_pair(integer(), literal("-"), integer());
+    map(min_max, |(min, max)| min..=max)(input)
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum TicketError {
+    #[error("could not parse ticket notes, {0}")]
+    Parse(#[from] ParseError<FormatError>),
+    #[error("field assignments are ambiguous, more than one possibility for column {0}")]
+    AmbiguousFieldMapping(usize),
+    #[error("the fields can not be assigned to the columns, each field to a different one")]
+    NoFieldMapping,
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum FormatError {
+    #[error("{0}")]
+    Syntax(#[from] SyntaxError),
+    #[error("all tickets must have the same number of values")]
+    IrregularTicketValues,
+}
+
+#[test]
+fn test_example_part1() {
+    let input = read_file("../assets/days/day16_example.txt").unwrap();
+    let ticket_translation = parse_ticket_translation(&input).unwrap();
+
+    // the values 4, 55, and 12 are are not valid for any field.
+    // Adding together all of the invalid values produces your ticket scanning error rate:
+    // 4 + 55 + 12 = 71.
+    assert_eq!(ticket_translation.scan_error_rate(), 71)
+}
+
+#[test]
+fn test_constraint_match_range() {
+    let constraint = Constraint {
+        ranges: vec![0..=10, 15..=20, 30..=40],
+    };
+    // must succeed
+    for i in &[0, 5, 10, 15, 17, 20, 30, 35, 40] {
+        assert!(constraint.matches_any_range(*i))
+    }
+
+    // must fail
+    for i in &[11, 14, 21, 25, 29, 41] {
+        assert!(!constraint.matches_any_range(*i))
+    }
+}
+
+#[test]
+fn test_example_part2() {
+    let input = read_file("../assets/days/day16_example_part2.txt").unwrap();
+    let ticket_translation = parse_ticket_translation(&input).unwrap();
+
+    let mappings = ticket_translation.valid_ticket_named_values().unwrap();
+    // Based on the nearby tickets in the above example, the first position must be row, the second
+    // position must be class, and the third position must be seat; you can conclude that in your
+    // ticket, class is 12, row is 11, and seat is 13.
+    assert_eq!(mappings.get("row"), Some(&11));
+    assert_eq!(mappings.get("class"), Some(&12));
+    assert_eq!(mappings.get("seat"), Some(&13));
+}
+
+#[test]
+fn test_field_mappings() {
+    let mappings = |rules: &str| {
+        let notes = format!("{}\n\nyour ticket:\n1,5\n\nnearby tickets:\n1,5", rules);
+        let translation = parse_ticket_translation(&notes).unwrap();
+        let mappings = translation.get_mappings().map(|m| {
+            let mut m: Vec<_> = m.into_iter().map(|(n, c)| (n.to_string(), c)).collect();
+            m.sort();
+            m
+        });
+        mappings
+    };
+    // the first column can only be a, no other field is left for the second column
+    assert!(matches!(
+        mappings("a: 1-1 or 5-5\nb: 2-2 or 6-6"),
+        Err(TicketError::NoFieldMapping)
+    ));
+    // both fields accept both values
+    assert!(matches!(
+        mappings("a: 1-1 or 5-5\nb: 1-1 or 5-5"),
+        Err(TicketError::AmbiguousFieldMapping(0))
+    ));
+    assert_eq!(
+        vec![("a".to_string(), 1), ("b".to_string(), 0)],
+        mappings("a: 1-5 or 7-7\nb: 1-1 or 7-7").unwrap()
+    );
+}
+
+#[test]
+fn test_field_mappings_without_single_candidate() {
+    // every column fits two fields and every field two columns, in a cycle a-b-c. Elimination
+    // can't assign any column, the matcher finds both ways around the cycle.
+    let notes = "a: 1-2 or 9-9\nb: 2-3 or 9-9\nc: 1-1 or 3-3\n\n\
+                 your ticket:\n1,2,3\n\nnearby tickets:\n1,2,3";
+    let translation = parse_ticket_translation(notes).unwrap();
+    let candidates: BTreeMap<usize, HashSet<&str>> = translation
+        .candidate_mappings()
+        .into_iter()
+        .enumerate()
+        .collect();
+    assert!(matching::eliminate(candidates.clone()).assigned.is_empty());
+    let expected = vec![
+        vec![(0, "a"), (1, "b"), (2, "c")].into_iter().collect(),
+        vec![(0, "c"), (1, "a"), (2, "b")].into_iter().collect(),
+    ];
+    assert_eq!(
+        matching::Matching::Ambiguous(expected),
+        matching::solve(&candidates)
+    );
+    assert!(matches!(
+        translation.get_mappings(),
+        Err(TicketError::AmbiguousFieldMapping(0))
+    ));
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = |input: &str| match parse_ticket_translation(input) {
+        Err(TicketError::Parse(e)) => (e.line, e.column, e.error),
+        _ => panic!("expected a parse error"),
+    };
+    let rules = "class: 1-3 or 5-7\nrow: 6-11 or 33-44";
+    assert!(matches!(
+        error(&format!(
+            "{}\n\nyour ticket:\n7,1\n\nnearby tickets:\n7,3\n40,x",
+            rules
+        )),
+        (9, 3, FormatError::Syntax(SyntaxError::Trailing))
+    ));
+    assert!(matches!(
+        error(&format!(
+            "{}\n\nyour ticket:\n7,1\n\nnearby tickets:\n7,3\n40",
+            rules
+        )),
+        (9, 1, FormatError::IrregularTicketValues)
+    ));
+    // your ticket is checked too, named_values looks up its values by the columns of the others
+    assert!(matches!(
+        error(&format!(
+            "{}\n\nyour ticket:\n7\n\nnearby tickets:\n7,3\n40,1",
+            rules
+        )),
+        (8, 1, FormatError::IrregularTicketValues)
+    ));
+    assert!(matches!(
+        error("class: 1-3 or 5+7\n\nyour ticket:\n7\n\nnearby tickets:\n7"),
+        (1, 16, FormatError::Syntax(SyntaxError::Literal("-")))
+    ));
+    assert!(matches!(
+        error(&format!("{}\n\nyour ticket:\n7,1", rules)),
+        (5, 4, FormatError::Syntax(SyntaxError::Literal(_)))
+    ));
+}
@@ -0,0 +1,181 @@
+use helpers::automaton::{Automaton, Moore};
+use helpers::sparse::{Point, SparseGrid};
+use helpers::{ParseGridError, Solution, Unsolved};
+use std::convert::TryFrom;
+use thiserror::Error;
+use tracing::debug;
+use std::fmt::{Display, Formatter, Write};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input<'a> = Grid;
+    type Error = CubeError;
+    type Part1 = usize;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        parse_starting_state(input)
+    }
+
+    // Part 1
+    // Starting with your given initial configuration, simulate six cycles. How many cubes are left
+    // in the active state after the sixth cycle?
+    fn part1(grid: &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        Ok(grid.boot(6).count_active())
+    }
+
+    fn part2(_: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        Ok(Unsolved)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Cube {
+    Active,
+    Inactive,
+}
+
+// x, y, z
+type Coordinate = Point<3>;
+#[derive(Debug, Clone)]
+pub struct Grid {
+    cubes: SparseGrid<Cube, 3>,
+}
+
+impl Grid {
+    fn count_active(&self) -> usize {
+        self.cubes.len()
+    }
+
+    fn get_cube_at(&self, c: Coordinate) -> Cube {
+        *self.cubes.get(&c)
+    }
+
+    // Runs the given number of cycles, cubes with two or three active neighbours stay active and
+    // cubes with three active neighbours become active
+    fn boot(&self, cycles: usize) -> Grid {
+        let mut automaton = Automaton::new(self.cubes.clone(), Moore, |cube, neighbours| {
+            let active = neighbours.filter(|c| **c == Cube::Active).count();
+            match (cube, active) {
+                (Cube::Active, 2..=3) | (Cube::Inactive, 3) => Cube::Active,
+                _ => Cube::Inactive,
+            }
+        });
+        for cycle in 0..cycles {
+            if let Some(bounds) = automaton.state().bounds() {
+                debug!(cycle, min = ?bounds.min, max = ?bounds.max, "bounds");
+            }
+            automaton.step();
+        }
+        Grid {
+            cubes: automaton.into_state(),
+        }
+    }
+}
+
+impl Display for Grid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let bounds = match self.cubes.bounds() {
+            Some(bounds) => bounds,
+            None => return Ok(()),
+        };
+        let (min, max) = (bounds.min, bounds.max);
+        for z in min[2]..=max[2] {
+            writeln!(f, "Z={}", z)?;
+            for y in min[1]..=max[1] {
+                for x in min[0]..=max[0] {
+                    let c = match self.get_cube_at([x, y, z]) {
+                        Cube::Active => '#',
+                        Cube::Inactive => '.',
+                    };
+                    f.write_char(c)?;
+                }
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<char> for Cube {
+    type Error = UnknownCube;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Cube::Inactive),
+            '#' => Ok(Cube::Active),
+            _ => Err(UnknownCube(value)),
+        }
+    }
+}
+
+// the initial state is a flat slice at z = 0
+fn parse_starting_state(s: &str) -> Result<Grid, CubeError> {
+    let z = 0;
+    let slice: helpers::Grid<Cube> = s.parse()?;
+    let mut cubes = SparseGrid::new(Cube::Inactive);
+    for ((x, y), cube) in slice.items_iter() {
+        cubes.set([x as isize, y as isize, z], *cube);
+    }
+
+    Ok(Grid { cubes })
+}
+
+#[derive(Debug, Clone, Error)]
+pub enum CubeError {
+    #[error("could not parse initial state, {0}")]
+    InitialStateError(#[from] ParseGridError<UnknownCube>),
+}
+
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+#[error("unknown cube {0}, only '#' and '.' are allowed")]
+pub struct UnknownCube(char);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EXAMPLE: &str = ".#.\n..#\n###";
+    #[test]
+    fn test_parse_grid() {
+        let grid = parse_starting_state(EXAMPLE).unwrap();
+
+        let z = 0;
+        for (x, y) in &[(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)] {
+            assert_eq!(*grid.cubes.get(&[*x, *y, z]), Cube::Active);
+        }
+    }
+
+    // Each cube only ever considers its neighbors: any of the 26 other cubes where any of their
+    // coordinates differ by at most 1. For example, given the cube at x=1,y=2,z=3, its neighbors
+    // include the cube at x=2,y=2,z=2, the cube at x=0,y=2,z=3, and so on.
+    #[test]
+    fn test_get_neighbours() {
+        let grid = parse_starting_state(EXAMPLE).unwrap();
+
+        let neighbours= grid.cubes.neighbours([1, 1, 0]).filter(|(_, c)|**c == Cube::Active).count();
+        assert_eq!(5, neighbours);
+
+        // the bottom center cube sees the right cube above it and both of its horizontal neighbours
+        let neighbours= grid.cubes.neighbours([1, 2, 0]).filter(|(_, c)|**c == Cube::Active).count();
+        assert_eq!(3, neighbours);
+    }
+
+    #[test]
+    fn test_example_part1() {
+        let grid = parse_starting_state(EXAMPLE).unwrap();
+
+        // println!("{}", grid);
+        let cycle1 = grid.boot(1);
+        // println!("Cycle1: \n{}", cycle1);
+        let cycle2 = cycle1.boot(1);
+        // println!("Cycle2: \n{}", cycle2);
+        assert_eq!(21, cycle2.count_active());
+        let grid = cycle2.boot(4);
+
+        let count = grid.count_active();
+        assert_eq!(112, count);
+    }
+}
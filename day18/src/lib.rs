@@ -0,0 +1,193 @@
+use helpers::{ParseError, Solution, Unsolved};
+use std::iter::Peekable;
+use std::str::CharIndices;
+use thiserror::Error;
+use tracing::{debug, trace};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    type Input<'a> = Vec<Vec<Token>>;
+    type Error = ParseError<OperationError>;
+    type Part1 = Unit;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        input
+            .lines()
+            .map(|equation| parse_equation(input, equation))
+            .collect()
+    }
+
+    // Part 1
+    // Before you can help with the homework, you need to understand it yourself. Evaluate the
+    // expression on each line of the homework; what is the sum of the resulting values?
+    fn part1(equations: &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        Ok(equations
+            .iter()
+            .map(|tokens| evaluate_with_same_precedence(tokens))
+            .sum())
+    }
+
+    fn part2(_: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        Ok(Unsolved)
+    }
+}
+
+type Unit = u64;
+
+fn evaluate_with_same_precedence(tokens: &[Token]) -> Unit {
+    let mut value = 0;
+    let mut operator = Operator::Add;
+    for t in tokens {
+        match t {
+            Token::Value(v) => match operator {
+                Operator::Add => value += v,
+                Operator::Mult => value *= v,
+            },
+            Token::Operator(op) => operator = *op,
+            Token::Par(values) => {
+                let v = evaluate_with_same_precedence(values);
+                match operator {
+                    Operator::Add => value += v,
+                    Operator::Mult => value *= v,
+                }
+            }
+        }
+    }
+    value
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum Operator {
+    Add,
+    Mult,
+}
+
+#[derive(Clone, Debug)]
+pub enum Token {
+    Value(Unit),
+    Operator(Operator),
+    Par(Vec<Token>),
+}
+
+// Tokenizes an equation, which is a line of the input
+fn parse_equation(input: &str, equation: &str) -> Result<Vec<Token>, ParseError<OperationError>> {
+    parse_tokens(equation, &mut equation.char_indices().peekable(), None)
+        .map_err(|(span, e)| ParseError::at(input, span, e))
+}
+
+// Reads tokens until the end of the equation, or until the closing parenthesis if there is an
+// open one. Errors contain the failing part of the equation.
+fn parse_tokens<'a>(
+    equation: &'a str,
+    iter: &mut Peekable<CharIndices>,
+    open: Option<&'a str>,
+) -> Result<Vec<Token>, (&'a str, OperationError)> {
+    let mut tokens = Vec::new();
+    while let Some((start, next)) = iter.next() {
+        trace!(position = start, char = %next, "token");
+        let t = match next {
+            '0'..='9' => {
+                // read whole number
+                let mut end = start + 1;
+                while let Some((_, '0'..='9')) = iter.peek() {
+                    iter.next();
+                    end += 1;
+                }
+                let number = &equation[start..end];
+                let number = number
+                    .parse()
+                    .map_err(|_| (number, OperationError::NumberTooLarge))?;
+                Some(Token::Value(number))
+            }
+            '+' => Some(Token::Operator(Operator::Add)),
+            '*' => Some(Token::Operator(Operator::Mult)),
+            // recurse
+            '(' => {
+                let open = &equation[start..=start];
+                Some(Token::Par(parse_tokens(equation, iter, Some(open))?))
+            }
+            // go up
+            ')' if open.is_some() => {
+                return Ok(tokens);
+            }
+            ')' => return Err((&equation[start..=start], OperationError::UnmatchedParenthesis)),
+            ' ' => None,
+            c => {
+                let span = &equation[start..start + c.len_utf8()];
+                return Err((span, OperationError::Tokenizer(c)));
+            }
+        };
+        if let Some(token) = t {
+            tokens.push(token);
+        }
+    }
+    if let Some(open) = open {
+        return Err((open, OperationError::UnclosedParenthesis));
+    }
+    debug!(?tokens, "equation");
+    Ok(tokens)
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum OperationError {
+    #[error("could not tokenize {0}")]
+    Tokenizer(char),
+    #[error("number is too large")]
+    NumberTooLarge,
+    #[error("parenthesis is never closed")]
+    UnclosedParenthesis,
+    #[error("closing parenthesis without an opening one")]
+    UnmatchedParenthesis,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_precedence() {
+        // For example, the steps to evaluate the expression 1 + 2 * 3 + 4 * 5 + 6 are as follows:
+        // 1 + 2 * 3 + 4 * 5 + 6
+        //   3   * 3 + 4 * 5 + 6
+        //       9   + 4 * 5 + 6
+        //          13   * 5 + 6
+        //              65   + 6
+        //                  71
+        let input = "1 + 2 * 3 + 4 * 5 + 6";
+        let straight = parse_equation(input, input).unwrap();
+
+        let input = "1 + (2 * 3) + (4 * (5 + 6))";
+        let parentheses = parse_equation(input, input).unwrap();
+        // Parentheses can override this order; for example, here is what happens if parentheses are added to form 1 + (2 * 3) + (4 * (5 + 6)):
+        //
+        // 1 + (2 * 3) + (4 * (5 + 6))
+        // 1 +    6    + (4 * (5 + 6))
+        //      7      + (4 * (5 + 6))
+        //      7      + (4 *   11   )
+        //      7      +     44
+        //             51
+        assert_eq!(71, evaluate_with_same_precedence(&straight));
+        assert_eq!(51, evaluate_with_same_precedence(&parentheses));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Day18::parse("1 + 2\n3 - 4").unwrap_err();
+        assert_eq!((2, 3, 1), (error.line, error.column, error.len));
+        assert!(matches!(error.error, OperationError::Tokenizer('-')));
+
+        let error = Day18::parse("1 + (2 * (3 + 4)").unwrap_err();
+        assert_eq!((1, 5), (error.line, error.column));
+        assert!(matches!(error.error, OperationError::UnclosedParenthesis));
+
+        let error = Day18::parse("1 + 2) * 3").unwrap_err();
+        assert_eq!((1, 6), (error.line, error.column));
+        assert!(matches!(error.error, OperationError::UnmatchedParenthesis));
+
+        let error = Day18::parse("1 + 99999999999999999999").unwrap_err();
+        assert_eq!((1, 5, 20), (error.line, error.column, error.len));
+    }
+}
@@ -0,0 +1,231 @@
+use helpers::parser::{
+    alt, any_char, delimited, integer, lines, literal, map, separated, separated_pair, PResult,
+    SyntaxError,
+};
+use helpers::{parser, split_once, ParseError, Solution};
+use std::collections::HashMap;
+use thiserror::Error;
+use std::str::FromStr;
+use tracing::{debug, trace};
+
+// Part 2:
+// As you look over the list of messages, you realize your matching rules aren't quite right.
+// To fix them, completely replace rules 8: 42 and 11: 42 31 with the following:
+// 8: 42 | 42 8
+// 11: 42 31 | 42 11 31
+static PART2_RULES: &str = "8: 42 | 42 8\n11: 42 31 | 42 11 31";
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input<'a> = (Validator, Vec<&'a str>);
+    type Error = ValidatorError;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        let (rules, messages) = split_once(input, "\n\n");
+        let validator = Validator::from_str(rules)?;
+        let messages: Vec<_> = messages.lines().collect();
+        debug!(rules = validator.rules.len(), messages = messages.len(), "rules and messages");
+        Ok((validator, messages))
+    }
+
+    // Part 1
+    // How many messages completely match rule 0?
+    fn part1((v, messages): &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        Ok(messages.iter().filter(|message| v.is_match(message)).count())
+    }
+
+    fn part2((v, messages): &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        let mut rules = v.rules.clone();
+        rules.extend(parse_rules(PART2_RULES)?);
+        let v = Validator::new(rules)?;
+
+        Ok(messages.iter().filter(|message| v.is_match(message)).count())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Validator {
+    rules: Rules
+}
+
+impl FromStr for Validator {
+    type Err = ValidatorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Validator::new(parse_rules(s)?)
+    }
+}
+
+impl Validator {
+    // Sanity check, guarantees rule 0 and all referenced rules to be there
+    fn new(rules: Rules) -> Result<Self, ValidatorError> {
+        rules.get(&0).ok_or(ValidatorError::MissingRule0)?;
+        if let Some(id) = rules.values().find_map(|p| p.unknown_reference(&rules)) {
+            return Err(ValidatorError::UnknownRule(id));
+        }
+        Ok(Self{rules})
+    }
+
+    pub fn is_match(&self, s: &str) -> bool {
+        let rule0 = self.rules.get(&0).unwrap();
+        let is_match = self.match_pattern(rule0, &[s]).contains(&"");
+        trace!(message = s, is_match, "message");
+        is_match
+    }
+
+    // returns the remainders of all candidates after matching the pattern
+    fn match_pattern<'a>(&self, pattern: &Pattern, candidates: &[&'a str]) -> Vec<&'a str> {
+        let mut new_candidates = Vec::new();
+        for s in candidates {
+            // nothing left to match, this candidate can't satisfy the pattern
+            if s.is_empty() {
+                continue
+            }
+            match pattern {
+                Pattern::Symbol(c) => {
+                    if let Some(rest) = s.strip_prefix(*c) {
+                        new_candidates.push(rest);
+                    }
+                },
+                Pattern::Rule(id) => {
+                    let pat = self.rules.get(id).unwrap();
+                    let mut cs = self.match_pattern(pat, &[*s]);
+                    new_candidates.append(&mut cs);
+                }
+                Pattern::And(rs) => {
+                    let mut rest = vec![*s];
+                    for pat in rs {
+                        rest = self.match_pattern(pat, &rest);
+                    }
+                    new_candidates.append(&mut rest);
+                }
+                Pattern::Or(rs) => {
+                    for pat in rs {
+                        let mut mine = self.match_pattern(pat, &[*s]);
+                        new_candidates.append(&mut mine)
+                    }
+                }
+            };
+        }
+        new_candidates
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Pattern {
+    Symbol(char),
+    Rule(Id),
+    And(Vec<Pattern>),
+    Or(Vec<Pattern>)
+}
+
+impl Pattern {
+    // the first referenced rule which is not part of rules
+    fn unknown_reference(&self, rules: &Rules) -> Option<Id> {
+        match self {
+            Pattern::Symbol(_) => None,
+            Pattern::Rule(id) => Some(*id).filter(|id| !rules.contains_key(id)),
+            Pattern::And(ps) | Pattern::Or(ps) => ps.iter().find_map(|p| p.unknown_reference(rules)),
+        }
+    }
+}
+
+type Id = u32;
+type Rules = HashMap<Id, Pattern>;
+
+fn parse_rules(s: &str) -> Result<Rules, ParseError<RuleError>> {
+    let rules = parser::parse(s, lines(rule)).map_err(|e| e.map(RuleError::from))?;
+    Ok(rules.into_iter().collect())
+}
+
+// 1: 2 3 | 3 2
+fn rule(input: &str) -> PResult<'_, (Id, Pattern)> {
+    separated_pair(integer(), literal(": "), parse_pattern)(input)
+}
+
+// alternatives of sequences of symbols "a" and rule ids
+fn parse_pattern(input: &str) -> PResult<'_, Pattern> {
+    let symbol = map(delimited(literal("\""), any_char, literal("\"")), Pattern::Symbol);
+    let rule = map(integer(), Pattern::Rule);
+    let sequence = map(separated(alt(symbol, rule), literal(" ")), |mut v| if v.len() == 1 {
+        v.remove(0)
+    } else {
+        Pattern::And(v)
+    });
+    map(separated(sequence, literal(" | ")), |mut v| if v.len() == 1 {
+        v.remove(0)
+    } else {
+        Pattern::Or(v)
+    })(input)
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum ValidatorError {
+    #[error("could not parse rules, {0}")]
+    Parse(#[from] ParseError<RuleError>),
+    #[error("no rule 0")]
+    MissingRule0,
+    #[error("rule {0} is referenced, but not defined")]
+    UnknownRule(Id),
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum RuleError {
+    #[error("{0}")]
+    Syntax(#[from] SyntaxError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use helpers::read_file;
+
+    #[test]
+    fn test_example_part1() {
+        let input = read_file("../assets/days/day19_example.txt").unwrap();
+        let (rules, _) = split_once(&input, "\n\n");
+        let v = Validator::from_str(rules).unwrap();
+
+        //In the above example, ababbb and abbbab match, but bababa, aaabbb, and aaaabbb do not
+        assert!(v.is_match("ababbb"));
+        assert!(v.is_match("abbbab"));
+        assert!(!v.is_match("bababa"));
+        assert!(!v.is_match("aaabbb"));
+        assert!(!v.is_match("aaaabbb"));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let input = read_file("../assets/days/day19_example_part2.txt").unwrap();
+        let input = Day19::parse(&input).unwrap();
+
+        // Without updating rules 8 and 11, these rules only match three messages
+        assert_eq!(3, Day19::part1(&input).unwrap());
+        // However, after updating rules 8 and 11, a total of 12 messages match
+        assert_eq!(12, Day19::part2(&input).unwrap());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |rules| match Validator::from_str(rules) {
+            Err(ValidatorError::Parse(e)) => (e.line, e.column, e.error),
+            _ => panic!("expected a parse error"),
+        };
+        assert!(matches!(error("0: 1 2\n1: \"a\n2: \"b\""), (2, 6, RuleError::Syntax(SyntaxError::Literal("\"")))));
+        assert!(matches!(error("0: 1 2\n1 \"a\""), (2, 2, RuleError::Syntax(SyntaxError::Literal(": ")))));
+        assert!(matches!(error("0: 1 x"), (1, 5, RuleError::Syntax(SyntaxError::Trailing))));
+        assert!(matches!(Validator::from_str("1: \"a\""), Err(ValidatorError::MissingRule0)));
+        assert!(matches!(Validator::from_str("0: 1 2\n1: \"a\""), Err(ValidatorError::UnknownRule(2))));
+    }
+}
+/*
+0: 4 1 5
+1: 2 3 | 3 2
+2: 4 4 | 5 5
+3: 4 5 | 5 4
+4: "a"
+5: "b"*/
\ No newline at end of file
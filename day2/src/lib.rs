@@ -1,18 +1,38 @@
+use helpers::parser::{
+    any_char, integer, lines, literal, map, rest_of_line, separated_pair, verify, PResult,
+    SyntaxError,
+};
+use helpers::{parser, ParseError, Solution};
 use lazy_static::lazy_static;
 use regex::Regex;
-use std::error::Error;
 
-use crate::PolicyError::ParsePolicy;
 use std::num::ParseIntError;
 use thiserror::Error;
+use tracing::debug;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = helpers::read_file("assets/days/day2.txt")?;
-    let policies: Result<Vec<_>, _> = input.lines().map(|l| parse_policy(l)).collect();
-    let policies = policies?;
-    println!("Fulfilled day1 policy count: {}", day1(&policies));
-    println!("Fulfilled day2 policy count: {}", day2(&policies));
-    Ok(())
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input<'a> = Vec<Policy<'a>>;
+    type Error = ParseError<PolicyError>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        let policies =
+            parser::parse(input, lines(policy)).map_err(|e| e.map(PolicyError::from))?;
+        debug!(policies = policies.len(), "passwords");
+        Ok(policies)
+    }
+
+    fn part1(policies: &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        Ok(day1(policies))
+    }
+
+    fn part2(policies: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        Ok(day2(policies))
+    }
 }
 
 // Each line gives the password policy and then the password. The password policy indicates the
@@ -42,16 +62,16 @@ fn day2(policies: &[Policy]) -> usize {
 pub enum PolicyError {
     #[error("could not parse password policy from `{0}`")]
     ParsePolicy(String),
-    #[error("could not parse positional argument")]
+    #[error("could not parse positional argument, {0}")]
     ParseUint(#[from] ParseIntError),
-    #[error("policy index out of bounds")]
-    ValidateDay2Indexing,
+    #[error("{0}")]
+    Syntax(#[from] SyntaxError),
     #[error("unknown password policy error")]
     Unknown,
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Policy<'a> {
+pub struct Policy<'a> {
     lower_bound: usize,
     upper_bound: usize,
     letter: char,
@@ -65,54 +85,58 @@ impl<'a> Policy<'a> {
     }
 
     fn is_day2_policy_fulfilled(&self) -> bool {
-        let mut chars = self.password.chars();
-        chars
-            .nth(self.lower_bound - 1)
+        // positions start at 1, there is no position 0
+        let char_at = |position: usize| {
+            position
+                .checked_sub(1)
+                .and_then(|index| self.password.chars().nth(index))
+        };
+        match (char_at(self.lower_bound), char_at(self.upper_bound)) {
             // first position must exist, or false is returned
-            .map_or(false, |char_at_lower| {
-                // reuse the existing iterator by calculating the distance from the first position to
-                // the second
-                let distance = self.upper_bound - self.lower_bound - 1;
-                chars.nth(distance).map_or_else(
-                    // second position must exist, or only the first requirement is considered
-                    || char_at_lower == self.letter,
-                    // if both exist, __exactly__ one of them must match the letter
-                    |char_at_upper| {
-                        (char_at_lower == self.letter || char_at_upper == self.letter)
-                            && char_at_upper != char_at_lower
-                    },
-                )
-            })
+            (None, _) => false,
+            // second position must exist and differ, or only the first requirement is considered
+            (Some(char_at_lower), None) => char_at_lower == self.letter,
+            (Some(char_at_lower), Some(_)) if self.lower_bound == self.upper_bound => {
+                char_at_lower == self.letter
+            }
+            // if both exist, __exactly__ one of them must match the letter
+            (Some(char_at_lower), Some(char_at_upper)) => {
+                (char_at_lower == self.letter) != (char_at_upper == self.letter)
+            }
+        }
     }
 }
 
 // Input each line contains `{lower_bound}-{upper_bound} {letter}: {password}`
-fn parse_policy(line: &str) -> Result<Policy, Box<dyn Error>> {
-    let dash = line.find('-').ok_or_else(|| ParsePolicy(line.to_owned()))?;
-    let first_space = line.find(' ').ok_or_else(|| ParsePolicy(line.to_owned()))?;
-    let colon = line.find(':').ok_or_else(|| ParsePolicy(line.to_owned()))?;
-
-    let lower_bound: usize = line[0..dash].parse()?;
-    let upper_bound: usize = line[dash + 1..first_space].parse()?;
-    let letter = line[first_space + 1..colon]
-        .chars()
-        .next()
-        .ok_or_else(|| ParsePolicy(line.to_owned()))?;
-    // need to skip the space after the colon
-    let password = &line[colon + 2..];
+fn policy(input: &str) -> PResult<'_, Policy<'_>> {
+    let bounds = separated_pair(integer(), literal("-"), integer());
+    // positions start at 1, a single position may be given twice
+    let bounds = verify(
+        bounds,
+        |&(lower, upper)| lower > 0 && upper >= lower,
+        "ascending positions starting at 1",
+    );
+    let rule = separated_pair(bounds, literal(" "), any_char);
+    let policy = separated_pair(rule, literal(": "), rest_of_line);
+    map(
+        policy,
+        |(((lower_bound, upper_bound), letter), password)| Policy {
+            lower_bound,
+            upper_bound,
+            letter,
+            password,
+        },
+    )(input)
+}
 
-    let policy = Policy {
-        lower_bound,
-        upper_bound,
-        letter,
-        password,
-    };
-    Ok(policy)
+#[cfg(test)]
+fn parse_policy(line: &str) -> Result<Policy<'_>, ParseError<SyntaxError>> {
+    parser::parse(line, policy)
 }
 
 // Input each line contains `{lower_bound}-{upper_bound} {letter}: {password}`
 #[allow(dead_code)]
-fn parse_policy_regex(line: &str) -> Result<Policy, PolicyError> {
+fn parse_policy_regex(line: &str) -> Result<Policy<'_>, PolicyError> {
     lazy_static! {
         static ref RE: Regex = Regex::new(r#"^(?P<lower_bound>[0-9]+)-(?P<upper_bound>[0-9]+)\s(?P<letter>[A-z]):\s(?P<password>.*)"#).unwrap();
     }
@@ -171,6 +195,25 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_parse_errors() {
+        let error = Day2::parse("1-3 a: abcde\n1-3 b cdefg").unwrap_err();
+        assert_eq!(
+            (2, 6, "1-3 b cdefg"),
+            (error.line, error.column, error.snippet.as_str())
+        );
+        assert!(matches!(
+            error.error,
+            PolicyError::Syntax(SyntaxError::Literal(": "))
+        ));
+
+        assert!(parse_policy("1-x a: abcde").is_err());
+        assert!(parse_policy("3-1 a: abcde").is_err());
+        assert!(parse_policy("0-1 a: abcde").is_err());
+        assert!(parse_policy("1-3 ab: abcde").is_err());
+        assert!(parse_policy("1-3 a:").is_err());
+    }
+
     #[test]
     fn test_parse_policy_regex() {
         let policy = parse_policy_regex("10-20 a: password").unwrap();
@@ -244,4 +287,22 @@ mod tests {
         };
         assert!(!e3.is_day2_policy_fulfilled());
     }
+
+    #[test]
+    fn test_same_position() {
+        let policy = parse_policy("2-2 a: aab").unwrap();
+        assert_eq!((2, 2), (policy.lower_bound, policy.upper_bound));
+        // the position is only checked once
+        assert!(policy.is_day2_policy_fulfilled());
+        // equal bounds require exactly that many letters
+        assert!(policy.is_day1_policy_fulfilled());
+
+        let policy = parse_policy("2-2 b: aab").unwrap();
+        assert!(!policy.is_day2_policy_fulfilled());
+        assert!(!policy.is_day1_policy_fulfilled());
+
+        // positions beyond the password are never fulfilled
+        let policy = parse_policy("4-4 a: aab").unwrap();
+        assert!(!policy.is_day2_policy_fulfilled());
+    }
 }
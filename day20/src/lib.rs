@@ -0,0 +1,423 @@
+use helpers::grid::Edge;
+use helpers::parser::{
+    blocks, delimited, integer, lines, literal, pair, spanned, take_while1, PResult, SyntaxError,
+};
+use helpers::{parser, Detailed, Grid, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+use thiserror::Error;
+use tracing::{debug, trace};
+
+// The pixels of a sea monster, every '#' has to be set in the image
+const SEA_MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Input<'a> = Vec<Tile>;
+    type Error = ImageError;
+    type Part1 = u64;
+    type Part2 = Detailed<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        let tiles = parse_tiles(input)?;
+        debug!(tiles = tiles.len(), "tiles");
+        Ok(tiles)
+    }
+
+    // Part 1
+    // Assemble the tiles into an image. What do you get if you multiply together the IDs of the
+    // four corner tiles?
+    fn part1(tiles: &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        let corners = Arrangement::new(tiles)?.corners();
+        corners
+            .iter()
+            .try_fold(1u64, |product, &id| product.checked_mul(id))
+            .ok_or(ImageError::CornerOverflow(corners))
+    }
+
+    // Part 2
+    // Determine how rough the waters are in the sea monsters' habitat by counting the number of #
+    // that are not part of a sea monster.
+    fn part2(tiles: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        let image = Arrangement::new(tiles)?.image();
+        let monster = sea_monster();
+        // the monsters only show up in one orientation of the image
+        for image in image.orientations() {
+            let (monsters, pixels) = find_sea_monsters(&image, &monster);
+            trace!(monsters, "orientation");
+            if monsters > 0 {
+                let roughness = image.iter().filter(|&&pixel| pixel).count() - pixels.len();
+                return Ok(Detailed::new(roughness).with("sea_monsters", monsters));
+            }
+        }
+        Err(ImageError::NoSeaMonster)
+    }
+}
+
+// A square camera image, '#' pixels are set
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tile {
+    id: u64,
+    pixels: Grid<bool>,
+}
+
+// The tiles of the image in reading order, each one turned into the orientation it is placed in
+#[derive(Debug)]
+struct Arrangement {
+    side: usize,
+    tiles: Vec<(u64, Grid<bool>)>,
+}
+
+impl Arrangement {
+    fn new(tiles: &[Tile]) -> Result<Self, ImageError> {
+        let side = (0..=tiles.len())
+            .find(|side| side * side >= tiles.len())
+            .unwrap_or_default();
+        if side * side != tiles.len() {
+            return Err(ImageError::NotSquare(tiles.len()));
+        }
+
+        let mut search = Search::new(tiles, side);
+        if !search.place() {
+            return Err(ImageError::NoArrangement);
+        }
+        let tiles = search
+            .placed
+            .iter()
+            .map(|&(tile, orientation)| {
+                let pixels = search.orientations[tile][orientation].clone();
+                (tiles[tile].id, pixels)
+            })
+            .collect();
+        debug!(side, "arrangement");
+        Ok(Arrangement { side, tiles })
+    }
+
+    fn corners(&self) -> [u64; 4] {
+        let last = self.side - 1;
+        [0, last, last * self.side, last * self.side + last].map(|i| self.tiles[i].0)
+    }
+
+    // The tiles next to each other without their borders, the borders only served to match them
+    fn image(&self) -> Grid<bool> {
+        let inner = self.tiles[0].1.num_columns() - 2;
+        let mut image = Grid::filled(self.side * inner, self.side * inner, false);
+        for (i, (_, pixels)) in self.tiles.iter().enumerate() {
+            let pixels = pixels
+                .sub_grid(1, 1, inner, inner)
+                .expect("tiles are at least 3 pixels wide");
+            let (col, row) = (i % self.side * inner, i / self.side * inner);
+            image
+                .paste(&pixels, col, row)
+                .expect("the image fits all tiles");
+        }
+        image
+    }
+}
+
+// Backtracking search for an arrangement, the tiles are placed in reading order. Every tile has
+// to match the right edge of its left neighbour and the bottom edge of the tile above it.
+struct Search {
+    side: usize,
+    // all 8 orientations of every tile
+    orientations: Vec<Vec<Grid<bool>>>,
+    // (tile, orientation) by the left and the top edge of the oriented tile
+    by_left: HashMap<Vec<bool>, Vec<(usize, usize)>>,
+    by_top: HashMap<Vec<bool>, Vec<(usize, usize)>>,
+    placed: Vec<(usize, usize)>,
+    used: Vec<bool>,
+}
+
+impl Search {
+    fn new(tiles: &[Tile], side: usize) -> Self {
+        let orientations: Vec<Vec<_>> = tiles
+            .iter()
+            .map(|tile| tile.pixels.orientations().collect())
+            .collect();
+        let mut by_left: HashMap<_, Vec<_>> = HashMap::new();
+        let mut by_top: HashMap<_, Vec<_>> = HashMap::new();
+        for (tile, oriented) in orientations.iter().enumerate() {
+            for (orientation, pixels) in oriented.iter().enumerate() {
+                let key = (tile, orientation);
+                by_left
+                    .entry(pixels.edge(Edge::Left))
+                    .or_default()
+                    .push(key);
+                by_top.entry(pixels.edge(Edge::Top)).or_default().push(key);
+            }
+        }
+        Search {
+            side,
+            orientations,
+            by_left,
+            by_top,
+            placed: Vec::with_capacity(tiles.len()),
+            used: vec![false; tiles.len()],
+        }
+    }
+
+    fn pixels(&self, (tile, orientation): (usize, usize)) -> &Grid<bool> {
+        &self.orientations[tile][orientation]
+    }
+
+    // true once all tiles are placed
+    fn place(&mut self) -> bool {
+        let position = self.placed.len();
+        if position == self.used.len() {
+            return true;
+        }
+        let (col, row) = (position % self.side, position / self.side);
+        let above = position.checked_sub(self.side).map(|i| self.placed[i]);
+
+        // only tiles matching a neighbour can be placed, the first tile can be anything
+        let candidates = if col > 0 {
+            let left = self.pixels(self.placed[position - 1]).edge(Edge::Right);
+            self.by_left.get(&left).cloned().unwrap_or_default()
+        } else if let Some(above) = above {
+            let above = self.pixels(above).edge(Edge::Bottom);
+            self.by_top.get(&above).cloned().unwrap_or_default()
+        } else {
+            (0..self.used.len())
+                .flat_map(|tile| (0..8).map(move |orientation| (tile, orientation)))
+                .collect()
+        };
+
+        for candidate in candidates {
+            if self.used[candidate.0] {
+                continue;
+            }
+            if let Some(above) = above.filter(|_| col > 0 && row > 0) {
+                let bottom = self.pixels(above).edge(Edge::Bottom);
+                if bottom != self.pixels(candidate).edge(Edge::Top) {
+                    continue;
+                }
+            }
+            self.used[candidate.0] = true;
+            self.placed.push(candidate);
+            if self.place() {
+                return true;
+            }
+            self.placed.pop();
+            self.used[candidate.0] = false;
+        }
+        false
+    }
+}
+
+// (column, row) offsets of the sea monster pixels
+fn sea_monster() -> Vec<(usize, usize)> {
+    SEA_MONSTER
+        .iter()
+        .enumerate()
+        .flat_map(|(row, line)| {
+            line.char_indices()
+                .filter(|&(_, c)| c == '#')
+                .map(move |(col, _)| (col, row))
+        })
+        .collect()
+}
+
+// Returns the number of sea monsters and all pixels which are part of one. Sea monsters may
+// overlap, shared pixels are counted once.
+fn find_sea_monsters(
+    image: &Grid<bool>,
+    monster: &[(usize, usize)],
+) -> (usize, HashSet<(usize, usize)>) {
+    let width = monster.iter().map(|&(col, _)| col + 1).max().unwrap_or(0);
+    let height = monster.iter().map(|&(_, row)| row + 1).max().unwrap_or(0);
+    let mut monsters = 0;
+    let mut pixels = HashSet::new();
+    for row in 0..(image.num_rows() + 1).saturating_sub(height) {
+        for col in 0..(image.num_columns() + 1).saturating_sub(width) {
+            let is_monster = monster
+                .iter()
+                .all(|&(c, r)| image.get(col + c, row + r) == Some(&true));
+            if is_monster {
+                monsters += 1;
+                pixels.extend(monster.iter().map(|&(c, r)| (col + c, row + r)));
+            }
+        }
+    }
+    (monsters, pixels)
+}
+
+fn parse_tiles(input: &str) -> Result<Vec<Tile>, ParseError<TileError>> {
+    let parsed = parser::parse(input, blocks(tile)).map_err(|e| e.map(TileError::from))?;
+    let size = parsed.first().map_or(0, |(_, rows)| rows.len());
+    let mut ids = HashSet::new();
+    parsed
+        .into_iter()
+        .map(|((id, header), rows)| {
+            let error = |span, error| ParseError::at(input, span, error);
+            if rows.len() < 3 {
+                return Err(error(header, TileError::TooSmall(rows.len())));
+            }
+            if rows.len() != size {
+                let uneven = TileError::UnevenSize {
+                    expected: size,
+                    found: rows.len(),
+                };
+                return Err(error(header, uneven));
+            }
+            if let Some(row) = rows.iter().find(|row| row.len() != size) {
+                let not_square = TileError::NotSquare {
+                    rows: size,
+                    columns: row.len(),
+                };
+                return Err(error(row, not_square));
+            }
+            if !ids.insert(id) {
+                return Err(error(header, TileError::DuplicateId(id)));
+            }
+            let pixels = rows.iter().flat_map(|row| row.bytes().map(|b| b == b'#'));
+            let pixels = Grid::with_items(pixels.collect(), size).expect("the tile is square");
+            Ok(Tile { id, pixels })
+        })
+        .collect()
+}
+
+// Tile 2311:
+// ..##.#..#.
+// ##..#.....
+fn tile(input: &str) -> PResult<'_, ((u64, &str), Vec<&str>)> {
+    let header = spanned(delimited(literal("Tile "), integer(), literal(":\n")));
+    let row = take_while1(|c| c == '#' || c == '.', "a row of '#' and '.'");
+    pair(header, lines(row))(input)
+}
+
+#[derive(Error, Clone, Debug)]
+pub enum ImageError {
+    #[error("could not parse tiles, {0}")]
+    Parse(#[from] ParseError<TileError>),
+    #[error("{0} tiles can not form a square image")]
+    NotSquare(usize),
+    #[error("the tiles can not be arranged so that all edges match")]
+    NoArrangement,
+    #[error("no orientation of the image shows a sea monster")]
+    NoSeaMonster,
+    #[error("the product of the corner tiles {0:?} does not fit into 64 bits")]
+    CornerOverflow([u64; 4]),
+}
+
+#[derive(Error, Clone, Debug, Eq, PartialEq)]
+pub enum TileError {
+    #[error("{0}")]
+    Syntax(#[from] SyntaxError),
+    #[error("the tile has {0} rows, tiles need a border and at least one pixel inside")]
+    TooSmall(usize),
+    #[error("the tile has {found} rows, expected {expected} like the first tile")]
+    UnevenSize { expected: usize, found: usize },
+    #[error("the row has {columns} pixels, tiles are square with {rows} rows")]
+    NotSquare { rows: usize, columns: usize },
+    #[error("tile {0} appears more than once")]
+    DuplicateId(u64),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use helpers::read_file;
+
+    #[test]
+    fn test_example_part1() {
+        let input = read_file("../assets/days/day20_example.txt").unwrap();
+        let tiles = Day20::parse(&input).unwrap();
+        let mut corners = Arrangement::new(&tiles).unwrap().corners();
+        corners.sort_unstable();
+        assert_eq!([1171, 1951, 2971, 3079], corners);
+        assert_eq!(20899048083289, Day20::part1(&tiles).unwrap());
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let input = read_file("../assets/days/day20_example.txt").unwrap();
+        let tiles = Day20::parse(&input).unwrap();
+        let image = Arrangement::new(&tiles).unwrap().image();
+        assert_eq!((24, 24), (image.num_columns(), image.num_rows()));
+
+        let answer = Day20::part2(&tiles).unwrap();
+        assert_eq!(273, answer.answer);
+        assert_eq!(vec![("sea_monsters", "2".to_string())], answer.details);
+    }
+
+    #[test]
+    fn test_unassembled_tiles() {
+        let tiles = Day20::parse("Tile 1:\n###\n#.#\n###\n\nTile 2:\n...\n...\n...").unwrap();
+        assert!(matches!(
+            Day20::part1(&tiles),
+            Err(ImageError::NotSquare(2))
+        ));
+
+        // the edges of a filled and an empty tile never match
+        let mut tiles = [tiles.clone(), tiles].concat();
+        tiles[2].id = 3;
+        tiles[3].id = 4;
+        assert!(matches!(
+            Day20::part1(&tiles),
+            Err(ImageError::NoArrangement)
+        ));
+        assert!(matches!(
+            Day20::part2(&tiles[..1].to_vec()),
+            Err(ImageError::NoSeaMonster)
+        ));
+
+        // a single tile is all four corners
+        let mut tiles = tiles[..1].to_vec();
+        tiles[0].id = 1 << 16;
+        assert!(matches!(
+            Day20::part1(&tiles),
+            Err(ImageError::CornerOverflow([65536, 65536, 65536, 65536]))
+        ));
+        tiles[0].id = (1 << 16) - 1;
+        assert_eq!(((1 << 16) - 1u64).pow(4), Day20::part1(&tiles).unwrap());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| match Day20::parse(input) {
+            Err(ImageError::Parse(e)) => (e.line, e.column, e.error),
+            _ => panic!("expected a parse error"),
+        };
+        let tile = "Tile 1:\n...\n.#.\n...\n\n";
+        assert_eq!(
+            (1, 6, TileError::Syntax(SyntaxError::Expected("a number"))),
+            error("Tile x:\n...")
+        );
+        assert_eq!(
+            (3, 2, TileError::Syntax(SyntaxError::Trailing)),
+            error("Tile 1:\n...\n.o.\n...")
+        );
+        assert_eq!((1, 1, TileError::TooSmall(2)), error("Tile 1:\n..\n.."));
+        assert_eq!(
+            (
+                6,
+                1,
+                TileError::UnevenSize {
+                    expected: 3,
+                    found: 4
+                }
+            ),
+            error(&(tile.to_string() + "Tile 2:\n....\n....\n....\n....")),
+        );
+        assert_eq!(
+            (
+                3,
+                1,
+                TileError::NotSquare {
+                    rows: 3,
+                    columns: 4
+                }
+            ),
+            error("Tile 1:\n...\n....\n...")
+        );
+        assert_eq!(
+            (6, 1, TileError::DuplicateId(1)),
+            error(&(tile.to_string() + tile))
+        );
+    }
+}
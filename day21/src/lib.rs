@@ -0,0 +1,171 @@
+use helpers::matching;
+use helpers::parser::{
+    delimited, lines, literal, map, opt, pair, separated, take_while1, PResult, Parser, SyntaxError,
+};
+use helpers::{parser, ParseError, Solution};
+use std::collections::{BTreeMap, HashSet};
+use thiserror::Error;
+use tracing::{debug, trace};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    type Input<'a> = Vec<Food<'a>>;
+    type Error = AllergenError;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        let foods = parser::parse(input, lines(food))?;
+        debug!(foods = foods.len(), "foods");
+        Ok(foods)
+    }
+
+    // Part 1
+    // Determine which ingredients cannot possibly contain any of the allergens in your list. How
+    // many times do any of those ingredients appear?
+    fn part1(foods: &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        let candidates = candidates(foods);
+        let suspicious: HashSet<&str> = candidates.values().flatten().copied().collect();
+        Ok(foods
+            .iter()
+            .flat_map(|food| &food.ingredients)
+            .filter(|ingredient| !suspicious.contains(*ingredient))
+            .count())
+    }
+
+    // Part 2
+    // Time to stock your raft with supplies. What is your canonical dangerous ingredient list?
+    fn part2(foods: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        // each allergen is found in exactly one ingredient
+        let elimination = matching::eliminate(candidates(foods));
+        if let Some((allergen, ingredients)) = elimination.unresolved.into_iter().next() {
+            return Err(if ingredients.is_empty() {
+                AllergenError::NoIngredient(allergen.to_string())
+            } else {
+                AllergenError::AmbiguousAllergen(allergen.to_string())
+            });
+        }
+        debug!(assigned = ?elimination.assigned, "allergens");
+        // sorted alphabetically by their allergen
+        let dangerous: Vec<&str> = elimination.assigned.values().copied().collect();
+        Ok(dangerous.join(","))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Food<'a> {
+    ingredients: Vec<&'a str>,
+    // only some of the allergens of the food are listed
+    allergens: Vec<&'a str>,
+}
+
+// The ingredients which may contain each allergen. An allergen is in one ingredient, so every food
+// listing the allergen contains that ingredient.
+fn candidates<'a>(foods: &[Food<'a>]) -> BTreeMap<&'a str, HashSet<&'a str>> {
+    let mut candidates: BTreeMap<&str, HashSet<&str>> = BTreeMap::new();
+    for food in foods {
+        let ingredients: HashSet<&str> = food.ingredients.iter().copied().collect();
+        for allergen in &food.allergens {
+            candidates
+                .entry(allergen)
+                .and_modify(|candidates| candidates.retain(|i| ingredients.contains(i)))
+                .or_insert_with(|| ingredients.clone());
+        }
+    }
+    trace!(?candidates, "candidates");
+    candidates
+}
+
+// mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
+fn food(input: &str) -> PResult<'_, Food<'_>> {
+    let ingredients = separated(word("an ingredient"), literal(" "));
+    let allergens = delimited(
+        literal(" (contains "),
+        separated(word("an allergen"), literal(", ")),
+        literal(")"),
+    );
+    let food = map(
+        pair(ingredients, opt(allergens)),
+        |(ingredients, allergens)| Food {
+            ingredients,
+            allergens: allergens.unwrap_or_default(),
+        },
+    );
+    food(input)
+}
+
+fn word<'a>(expected: &'static str) -> impl Parser<'a, &'a str> {
+    take_while1(|c| c.is_ascii_lowercase(), expected)
+}
+
+#[derive(Error, Clone, Debug)]
+pub enum AllergenError {
+    #[error("could not parse food list, {0}")]
+    Parse(#[from] ParseError<SyntaxError>),
+    #[error("no ingredient is in every food containing {0}")]
+    NoIngredient(String),
+    #[error("several ingredients may contain {0}")]
+    AmbiguousAllergen(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use helpers::read_file;
+
+    #[test]
+    fn test_example_part1() {
+        let input = read_file("../assets/days/day21_example.txt").unwrap();
+        let foods = Day21::parse(&input).unwrap();
+        let candidates = candidates(&foods);
+        assert_eq!(
+            vec!["dairy", "fish", "soy"],
+            candidates.keys().copied().collect::<Vec<_>>()
+        );
+        // kfcds, nhms, sbzzf, or trh can not contain an allergen, they appear five times
+        assert_eq!(5, Day21::part1(&foods).unwrap());
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let input = read_file("../assets/days/day21_example.txt").unwrap();
+        let foods = Day21::parse(&input).unwrap();
+        assert_eq!("mxmxvkd,sqjhc,fvjkl", Day21::part2(&foods).unwrap());
+    }
+
+    #[test]
+    fn test_unresolved_allergens() {
+        let part2 = |input| Day21::part2(&Day21::parse(input).unwrap());
+        assert!(matches!(
+            part2("a b (contains soy)\nb a (contains soy)"),
+            Err(AllergenError::AmbiguousAllergen(allergen)) if allergen == "soy"
+        ));
+        assert!(matches!(
+            part2("a (contains soy)\nb (contains soy)"),
+            Err(AllergenError::NoIngredient(allergen)) if allergen == "soy"
+        ));
+        assert_eq!("", part2("a b").unwrap());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| match Day21::parse(input) {
+            Err(AllergenError::Parse(e)) => (e.line, e.column, e.error),
+            _ => panic!("expected a parse error"),
+        };
+        assert_eq!(
+            (1, 19, SyntaxError::Trailing),
+            error("a b (contains soy)\n(contains fish)")
+        );
+        assert_eq!(
+            (1, 15, SyntaxError::Expected("an allergen")),
+            error("a b (contains )")
+        );
+        assert_eq!(
+            (1, 18, SyntaxError::Literal(")")),
+            error("a b (contains soy")
+        );
+    }
+}
@@ -1,20 +1,36 @@
-use helpers::read_file;
+use helpers::{ParseError, Solution, Unsolved};
 use itertools::{Itertools, MinMaxResult};
 use std::collections::VecDeque;
-use std::error::Error;
 use std::num::ParseIntError;
 use std::str::FromStr;
 use thiserror::Error;
+use tracing::trace;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = read_file("./assets/days/day22.txt")?;
-    let mut game = parse_game(&input)?;
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    type Input<'a> = Game<'a>;
+    type Error = CrabCombatError;
+    type Part1 = usize;
+    type Part2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        parse_game(input)
+    }
 
-    println!("{:?}", game);
-    let winner = game.play();
     // Part 1
-    println!("{} score: {}", winner.name, winner.get_score());
-    Ok(())
+    // Play the small crab in a game of Combat using the two decks you just dealt. What is the
+    // winning player's score?
+    fn part1(game: &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        let mut game = game.clone();
+        let winner = game.play();
+        Ok(winner.get_score())
+    }
+
+    fn part2(_: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        Ok(Unsolved)
+    }
 }
 
 type Card = u8;
@@ -48,17 +64,17 @@ impl<'a> Player<'a> {
 }
 
 #[derive(Debug, Clone)]
-struct Game<'a> {
+pub struct Game<'a> {
     round: usize,
     players: Vec<Player<'a>>,
 }
 
 impl<'a> Game<'a> {
-    fn play(&mut self) -> &Player {
+    fn play(&mut self) -> &Player<'_> {
         loop {
             let result = self.play_round();
             match result {
-                State::Continue => println!("Round: {}", self.round),
+                State::Continue => trace!(round = self.round, "round"),
                 State::Win(w) => return &self.players[w],
             }
         }
@@ -75,12 +91,12 @@ impl<'a> Game<'a> {
 
         next_cards.sort_by_key(|c| c.1);
 
-        println!("Cards: {:?}", next_cards);
+        trace!(cards = ?next_cards, "cards");
         if let Some((winner, _)) = next_cards.last() {
             // must be put in deck biggest to smallest
             for (_, card) in next_cards.iter().rev() {
                 if let Some(c) = card {
-                    println!("{} wins {}", self.players[*winner].name, c);
+                    trace!(player = self.players[*winner].name, card = c, "wins");
                     self.players[*winner].deck.push_back(*c)
                 }
             }
@@ -102,13 +118,12 @@ impl<'a> Game<'a> {
     }
     fn with_players(players: Vec<Player<'a>>) -> Result<Game<'a>, CrabCombatError> {
         // sanity check
-        match players.iter().map(|p| p.deck.iter()).flatten().minmax() {
+        match players.iter().flat_map(|p| p.deck.iter()).minmax() {
             MinMaxResult::MinMax(min, max) => {
                 for i in *min..=*max {
                     if players
                         .iter()
-                        .map(|p| p.deck.iter())
-                        .flatten()
+                        .flat_map(|p| p.deck.iter())
                         .filter(|c| **c == i)
                         .count()
                         != 1
@@ -126,29 +141,31 @@ impl<'a> Game<'a> {
     }
 }
 
-fn parse_game(input: &str) -> Result<Game, CrabCombatError> {
+fn parse_game(input: &str) -> Result<Game<'_>, CrabCombatError> {
     let players: Result<Vec<_>, _> = input
         .split("\n\n")
-        .map(|section| parse_player(section))
+        .map(|player| parse_player(input, player))
         .collect();
     Game::with_players(players?)
 }
 
-fn parse_player(input: &str) -> Result<Player, CrabCombatError> {
-    let mut lines = input.lines();
+fn parse_player<'a>(input: &str, player: &'a str) -> Result<Player<'a>, CrabCombatError> {
+    let mut lines = player.lines();
     let name = lines
         .next()
         .ok_or(CrabCombatError::MissingPlayerIdentifier)?
         .trim_end_matches(':');
-    let deck: Result<Deck, _> = lines.map(Card::from_str).collect();
+    let deck: Result<Deck, _> = lines
+        .map(|card| Card::from_str(card).map_err(|e| ParseError::at(input, card, e)))
+        .collect();
 
     Ok(Player { name, deck: deck? })
 }
 
 #[derive(Clone, Debug, Error)]
-enum CrabCombatError {
-    #[error("could not parse card")]
-    UnknownCard(#[from] ParseIntError),
+pub enum CrabCombatError {
+    #[error("could not parse card, {0}")]
+    UnknownCard(#[from] ParseError<ParseIntError>),
     #[error("missing player identifier")]
     MissingPlayerIdentifier,
     #[error("no complete card deck in players cards")]
@@ -1,34 +1,49 @@
-use std::error::Error;
+use helpers::{ParseError, Solution};
 use std::fmt::{Display, Formatter};
-use std::fs::read_to_string;
 use thiserror::Error;
+use tracing::debug;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = read_to_string("./assets/days/day23.txt")?;
-    let labels = parse_labels(&input)?;
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    type Input<'a> = Vec<Label>;
+    type Error = CupsError;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        let labels = parse_labels(input)?;
+        debug!(?labels, "labels");
+        Ok(labels)
+    }
 
     // Part 1
-    {
-        let mut cups = Cups::with_labels(&labels);
+    // Using your labeling, simulate 100 moves. What are the labels on the cups after cup 1?
+    fn part1(labels: &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        let mut cups = Cups::with_labels(labels);
         cups.play(100);
-        println!("Order: {}", cups.get_order_after_1());
+        debug!(%cups, "cups");
+        Ok(cups.get_order_after_1())
     }
 
     // Part 2
-    {
-        let mut cups = Cups::with_labels_and_fill(&labels, 1_000_000);
+    fn part2(labels: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        let mut cups = Cups::with_labels_and_fill(labels, 1_000_000);
         cups.play(10_000_000);
 
-        // Under the two cups that will end up immediately clockwise of cup 1. You can have them if
-        // you predict what the labels on those cups will be when the crab is finished.
+        // Under the two cups that will end up immediately clockwise of cup 1. You can have them
+        // if you predict what the labels on those cups will be when the crab is finished.
         // What do you get if you  multiply their labels together?
         let (p1, p2) = cups.get_two_cups_after_one();
-        println!("P1: {} P2: {} Product: {}", p1, p2, p1 * p2)
+        debug!(p1, p2, "cups after 1");
+        Ok(p1 * p2)
     }
-    Ok(())
 }
 
 type Label = usize;
+// the label is only kept for debug output
+#[allow(dead_code)]
 #[derive(Clone, Debug)]
 struct Cup {
     label: usize,
@@ -79,9 +94,12 @@ impl Cups {
             }
             let next = if let Some(next) = &numbers.peek() {
                 ***next
-            } else {
+            } else if max < n {
                 // the last entry should point to the first number filling up
                 max + 1
+            } else {
+                // there is nothing to fill up, the circle is closed
+                cur_pos
             };
             cups[*label] = Cup {
                 label: *label,
@@ -89,14 +107,16 @@ impl Cups {
             }
         }
         // fill up until the desired number of cups is reached
-        for i in max + 1..=n {
-            cups[i] = Cup {
+        for (i, cup) in cups.iter_mut().enumerate().skip(max + 1) {
+            *cup = Cup {
                 label: i,
                 next: i + 1,
             }
         }
-        // adjust the last cup to point to the beginning
-        cups[n].next = cur_pos;
+        // adjust the last filled up cup to point to the beginning
+        if max < n {
+            cups[n].next = cur_pos;
+        }
 
         // check for all between min and max for wrapping around
         Cups { cur_pos, cups }
@@ -183,23 +203,55 @@ impl Display for Cups {
     }
 }
 
+// A move picks up three cups and needs a destination cup besides the current one
+static MIN_CUPS: usize = 5;
+
+// The labels must contain all numbers from 1 to the number of cups
 fn parse_labels(s: &str) -> Result<Vec<Label>, CupsError> {
-    let labels: Option<Vec<Label>> = s
-        .chars()
-        .map(|c| c.to_digit(10).map(|d| d as usize))
-        .collect();
-    Ok(labels.ok_or(CupsError::ParseCup)?)
+    let line = s.trim();
+    let mut labels = Vec::new();
+    for (i, c) in line.char_indices() {
+        let label = c.to_digit(10).ok_or(LabelError::NotADigit(c));
+        let label = label.and_then(|label| match label as Label {
+            0 => Err(LabelError::OutOfRange),
+            label if labels.contains(&label) => Err(LabelError::Duplicate(label)),
+            label => Ok(label),
+        });
+        labels.push(label.map_err(|e| ParseError::at(s, &line[i..i + c.len_utf8()], e))?);
+    }
+    if labels.len() < MIN_CUPS {
+        return Err(ParseError::at(s, line, LabelError::TooFewCups).into());
+    }
+    // the labels are distinct and positive, a missing label shows as one larger than the count
+    if let Some(i) = labels.iter().position(|&label| label > labels.len()) {
+        return Err(ParseError::at(s, &line[i..=i], LabelError::OutOfRange).into());
+    }
+    Ok(labels)
 }
 
 #[derive(Clone, Debug, Error)]
-enum CupsError {
-    #[error("could not parse cup ids")]
-    ParseCup,
+pub enum CupsError {
+    #[error("could not parse cup labels, {0}")]
+    Parse(#[from] ParseError<LabelError>),
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum LabelError {
+    #[error("cup labels must be digits, found {0}")]
+    NotADigit(char),
+    #[error("cup {0} appears twice")]
+    Duplicate(Label),
+    #[error("cup labels must range from 1 to the number of cups")]
+    OutOfRange,
+    #[error("there must be at least 5 cups")]
+    TooFewCups,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
+    use std::collections::VecDeque;
 
     static TEST_INPUT: &str = "389125467";
     #[test]
@@ -241,4 +293,85 @@ mod tests {
         // multiplying these together produces 149245887792.
         assert_eq!(149245887792, p1 * p2);
     }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input| match parse_labels(input) {
+            Err(CupsError::Parse(e)) => (e.column, e.error),
+            _ => panic!("expected a parse error"),
+        };
+        assert!(matches!(
+            error("38912x467"),
+            (6, LabelError::NotADigit('x'))
+        ));
+        assert!(matches!(error("389125463"), (9, LabelError::Duplicate(3))));
+        assert!(matches!(error("389125407"), (8, LabelError::OutOfRange)));
+        assert!(matches!(error("38912547"), (3, LabelError::OutOfRange)));
+        assert!(matches!(error("3124"), (1, LabelError::TooFewCups)));
+    }
+
+    // Moves the cups around in a ring buffer, the current cup is always in front
+    fn play_naive(labels: &[Label], moves: usize) -> Vec<Label> {
+        let mut circle: VecDeque<_> = labels.iter().copied().collect();
+        for _ in 0..moves {
+            let current = circle.pop_front().unwrap();
+            let picked: Vec<_> = circle.drain(..3).collect();
+            let mut destination = current;
+            loop {
+                destination = if destination == 1 {
+                    labels.len()
+                } else {
+                    destination - 1
+                };
+                if !picked.contains(&destination) {
+                    break;
+                }
+            }
+            let position = circle.iter().position(|&c| c == destination).unwrap();
+            for (i, cup) in picked.into_iter().enumerate() {
+                circle.insert(position + 1 + i, cup);
+            }
+            circle.push_back(current);
+        }
+        let one = circle.iter().position(|&c| c == 1).unwrap();
+        circle.rotate_left(one);
+        circle.into_iter().skip(1).collect()
+    }
+
+    fn labels_after_1(cups: &Cups) -> Vec<Label> {
+        std::iter::successors(Some(cups.get_next(1)), |&cup| Some(cups.get_next(cup)))
+            .take_while(|&cup| cup != 1)
+            .take(cups.cups.len())
+            .collect()
+    }
+
+    fn permutation(cups: std::ops::RangeInclusive<usize>) -> impl Strategy<Value = Vec<Label>> {
+        cups.prop_flat_map(|n| Just((1..=n).collect::<Vec<_>>()).prop_shuffle())
+    }
+
+    proptest! {
+        #[test]
+        fn test_play_matches_simulation(
+            labels in permutation(MIN_CUPS..=9),
+            moves in 0..200_usize,
+        ) {
+            let mut cups = Cups::with_labels(&labels);
+            cups.play(moves);
+            prop_assert_eq!(play_naive(&labels, moves), labels_after_1(&cups));
+        }
+
+        // the cups behind the labels are numbered on from the highest label
+        #[test]
+        fn test_fill_matches_simulation(
+            labels in permutation(MIN_CUPS..=9),
+            filled in 0..20_usize,
+            moves in 0..200_usize,
+        ) {
+            let n = labels.len() + filled;
+            let mut cups = Cups::with_labels_and_fill(&labels, n);
+            cups.play(moves);
+            let all: Vec<_> = labels.iter().copied().chain(labels.len() + 1..=n).collect();
+            prop_assert_eq!(play_naive(&all, moves), labels_after_1(&cups));
+        }
+    }
 }
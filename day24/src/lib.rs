@@ -0,0 +1,158 @@
+use helpers::automaton::{Automaton, Hexagonal};
+use helpers::hex::{Hex, HexDirection};
+use helpers::parser::{lines, many1, Failure, PResult, SyntaxError};
+use helpers::sparse::SparseGrid;
+use helpers::{parser, ParseError, Solution};
+use tracing::debug;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    type Input<'a> = Vec<Vec<HexDirection>>;
+    type Error = ParseError<SyntaxError>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        let paths = parser::parse(input, lines(many1(direction)))?;
+        debug!(paths = paths.len(), "tiles to flip");
+        Ok(paths)
+    }
+
+    // Part 1
+    // Go through the renovation crew's list and determine which tiles they need to flip. After all
+    // of the instructions have been followed, how many tiles are left with the black side up?
+    fn part1(paths: &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        Ok(Floor::lay(paths).count_black())
+    }
+
+    // Part 2
+    // The tile floor in the lobby is meant to be a living art exhibit. Every day, the tiles are
+    // all flipped according to the rules. How many tiles will be black after 100 days?
+    fn part2(paths: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        Ok(Floor::lay(paths).exhibit(100).count_black())
+    }
+}
+
+// Every tile is white side up at first
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Color {
+    White,
+    Black,
+}
+
+#[derive(Clone, Debug)]
+struct Floor {
+    tiles: SparseGrid<Color, 2>,
+}
+
+impl Floor {
+    // Each path starts at the reference tile in the center of the room, the tile at its end is
+    // flipped
+    fn lay(paths: &[Vec<HexDirection>]) -> Self {
+        let mut tiles = SparseGrid::new(Color::White);
+        for path in paths {
+            let tile = path.iter().fold(Hex::default(), |hex, &d| hex.neighbour(d));
+            let flipped = match tiles.get(&tile.into()) {
+                Color::White => Color::Black,
+                Color::Black => Color::White,
+            };
+            tiles.set(tile.into(), flipped);
+        }
+        debug!(black = tiles.len(), "laid");
+        Floor { tiles }
+    }
+
+    fn count_black(&self) -> usize {
+        self.tiles.len()
+    }
+
+    // Flips the tiles once per day. A black tile with zero or more than 2 black neighbours is
+    // flipped to white, a white tile with exactly 2 black neighbours is flipped to black.
+    fn exhibit(&self, days: usize) -> Floor {
+        let mut automaton = Automaton::new(self.tiles.clone(), Hexagonal, |tile, neighbours| {
+            let black = neighbours.filter(|t| **t == Color::Black).count();
+            match (tile, black) {
+                (Color::Black, 1..=2) | (Color::White, 2) => Color::Black,
+                _ => Color::White,
+            }
+        });
+        automaton.run(days);
+        debug!(days, black = automaton.state().len(), "exhibit");
+        Floor {
+            tiles: automaton.into_state(),
+        }
+    }
+}
+
+// e, se, sw, w, nw, and ne, without delimiters between them
+fn direction(input: &str) -> PResult<'_, HexDirection> {
+    let directions = [
+        ("e", HexDirection::East),
+        ("se", HexDirection::SouthEast),
+        ("sw", HexDirection::SouthWest),
+        ("w", HexDirection::West),
+        ("nw", HexDirection::NorthWest),
+        ("ne", HexDirection::NorthEast),
+    ];
+    directions
+        .iter()
+        .find_map(|&(tag, direction)| Some((direction, input.strip_prefix(tag)?)))
+        .ok_or_else(|| {
+            let span = &input[..input.chars().next().map_or(0, char::len_utf8)];
+            Failure::new(
+                span,
+                SyntaxError::Expected("a direction, e, se, sw, w, nw or ne"),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use helpers::read_file;
+
+    #[test]
+    fn test_reference_tile() {
+        // esew flips the tile south east of the reference tile, nwwswee the reference tile itself
+        let paths = Day24::parse("esew\nnwwswee").unwrap();
+        let floor = Floor::lay(&paths);
+        assert_eq!(&Color::Black, floor.tiles.get(&[0, 1]));
+        assert_eq!(&Color::Black, floor.tiles.get(&[0, 0]));
+
+        let paths = Day24::parse("nwwswee\ne\nw\nwe").unwrap();
+        assert_eq!(2, Day24::part1(&paths).unwrap());
+    }
+
+    #[test]
+    fn test_example_part1() {
+        let input = read_file("../assets/days/day24_example.txt").unwrap();
+        let paths = Day24::parse(&input).unwrap();
+        assert_eq!(10, Day24::part1(&paths).unwrap());
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let input = read_file("../assets/days/day24_example.txt").unwrap();
+        let floor = Floor::lay(&Day24::parse(&input).unwrap());
+        let black = |days| floor.exhibit(days).count_black();
+        assert_eq!(
+            vec![15, 12, 25, 14, 23, 28, 41, 37, 49, 37],
+            (1..=10).map(black).collect::<Vec<_>>()
+        );
+        assert_eq!(2208, black(100));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input| {
+            let e = Day24::parse(input).unwrap_err();
+            (e.line, e.column, e.error)
+        };
+        let expected = SyntaxError::Expected("a direction, e, se, sw, w, nw or ne");
+        assert_eq!((1, 1, expected), error("n"));
+        assert_eq!((2, 3, SyntaxError::Trailing), error("e\nsen"));
+        assert_eq!((1, 2, SyntaxError::Trailing), error("e\n\nw"));
+    }
+}
@@ -1,28 +1,44 @@
-use helpers::read_file;
-use std::error::Error;
+use helpers::{ParseError, Solution, Unsolved};
 use thiserror::Error;
 use std::num::ParseIntError;
+use tracing::debug;
 
-fn main() -> Result<(), Box<dyn Error>>{
-    let input = read_file("./assets/days/day25.txt")?;
-    let (pk1, pk2) = parse_public_keys(&input)?;
-    let subject_number = 7;
+pub struct Day25;
 
-    // Part 1
-    // What encryption key is the handshake trying to establish?
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    type Input<'a> = (Unit, Unit);
+    type Error = ComboBreakerError;
+    type Part1 = Unit;
+    type Part2 = Unsolved;
 
-    // Given 7 as subject number, find the loop size to generate the encryption key
-    let ls = find_loop_size(subject_number, pk1);
-    // Generate the encryption key by applying loop size times the transformation to the
-    // other party's public key
-    let encryption_key = find_encryption_key(ls, pk2);
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        let keys = parse_public_keys(input)?;
+        debug!(card = keys.0, door = keys.1, "public keys");
+        Ok(keys)
+    }
 
-    println!("Encryption key: {}", encryption_key);
+    // Part 1
+    // What encryption key is the handshake trying to establish?
+    fn part1(&(pk1, pk2): &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        let subject_number = 7;
+
+        // Given 7 as subject number, find the loop size to generate the encryption key
+        let ls = find_loop_size(subject_number, pk1);
+        debug!(loop_size = ls, "card");
+        // Generate the encryption key by applying loop size times the transformation to the
+        // other party's public key
+        Ok(find_encryption_key(ls, pk2))
+    }
 
-    Ok(())
+    // Day 25 has no second puzzle
+    fn part2(_: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        Ok(Unsolved)
+    }
 }
 
 type Unit = u64;
+static MODULUS: Unit = 20201227;
 
 // The handshake used by the card and the door involves an operation that transforms a subject
 // number.
@@ -52,25 +68,43 @@ fn transform(mut value: Unit, subject_number: Unit) -> Unit {
     //     Set the value to itself multiplied by the subject number.
     value *= subject_number;
     //     Set the value to the remainder after dividing the value by 20201227.
-    value % 20201227
+    value % MODULUS
 }
 
 fn parse_public_keys(s: &str) -> Result<(Unit, Unit), ComboBreakerError> {
     let mut lines = s.lines();
     if let Some(p1) = lines.next() {
         if let Some(p2) = lines.next() {
-            return Ok((p1.parse()?,p2.parse()?))
+            return Ok((parse_public_key(s, p1)?, parse_public_key(s, p2)?))
         }
     }
-    Err(ComboBreakerError::PublicKeyNotFound)
+    Err(ParseError::end(s, KeyError::PublicKeyNotFound).into())
+}
+
+// Only keys between 1 and the modulus can be generated, searching the loop size of others would
+// never end
+fn parse_public_key(s: &str, key: &str) -> Result<Unit, ParseError<KeyError>> {
+    match key.parse() {
+        Ok(pk) if (1..MODULUS).contains(&pk) => Ok(pk),
+        Ok(_) => Err(ParseError::at(s, key, KeyError::OutOfRange)),
+        Err(e) => Err(ParseError::at(s, key, e.into())),
+    }
+}
+
+#[derive(Clone, Debug, Error)]
+pub enum ComboBreakerError {
+    #[error("could not parse public keys, {0}")]
+    Parse(#[from] ParseError<KeyError>),
 }
 
 #[derive(Clone, Debug, Error)]
-enum ComboBreakerError {
+pub enum KeyError {
     #[error("could not get public keys")]
     PublicKeyNotFound,
-    #[error("could not parse public key")]
-    ParsePublicKey(#[from] ParseIntError)
+    #[error("could not parse public key, {0}")]
+    ParsePublicKey(#[from] ParseIntError),
+    #[error("public keys must be between 1 and 20201226")]
+    OutOfRange,
 }
 
 #[cfg(test)]
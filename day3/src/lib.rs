@@ -1,19 +1,27 @@
-use helpers::read_file;
-use std::convert::{TryFrom, TryInto};
-use std::error::Error;
+use helpers::{Grid, ParseGridError, Solution};
+use std::convert::TryFrom;
 use thiserror::Error;
+use tracing::debug;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = read_file("./assets/days/day3.txt");
-    let grid = Grid::from_input(&input?)?;
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input<'a> = Map;
+    type Error = MapError;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        Map::from_input(input)
+    }
 
     // Part 1:
     // Starting at the top-left corner of your map and following a slope of right 3 and down 1,
     // how many trees would you encounter?
-    println!(
-        "Encountered Trees with slope 3,1 => {}",
-        grid.count_trees_in_slope(3, 1)
-    );
+    fn part1(map: &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        Ok(map.count_trees_in_slope(3, 1))
+    }
 
     // Part 2:
     // Determine the number of trees you would encounter if, for each of the following slopes,
@@ -26,15 +34,13 @@ fn main() -> Result<(), Box<dyn Error>> {
     //     Right 1, down 2.
     //
     // What do you get if you multiply together the number of trees encountered on each of the listed slopes?
-    let product: usize = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
-        .iter()
-        .map(|(x, y)| grid.count_trees_in_slope(*x, *y))
-        .product();
-    println!(
-        "Product of encountered trees for slopes 1,1 3,1 5,1 7,1 1,2 => {}",
-        product
-    );
-    Ok(())
+    fn part2(map: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        let product = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+            .iter()
+            .map(|(x, y)| map.count_trees_in_slope(*x, *y))
+            .product();
+        Ok(product)
+    }
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -52,13 +58,13 @@ impl Square {
 // # stands for Tree
 // . stands for Empty
 impl TryFrom<char> for Square {
-    type Error = GridError;
+    type Error = UnknownSquare;
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
             '#' => Ok(Square::Tree),
             '.' => Ok(Square::Empty),
-            _ => Err(GridError::ParseSquare(value)),
+            _ => Err(UnknownSquare(value)),
         }
     }
 }
@@ -66,34 +72,28 @@ impl TryFrom<char> for Square {
 // due to something you read about once involving arboreal genetics and biome stability,
 // the same pattern repeats to the right many times
 #[derive(Clone, Debug, Eq, PartialEq)]
-struct Grid {
-    nodes: Vec<Vec<Square>>,
+pub struct Map {
+    grid: Grid<Square>,
 }
 
-impl Grid {
+impl Map {
     // A map (your puzzle input) of the open squares (.) and trees (#)
-    fn from_input(input: &str) -> Result<Self, GridError> {
-        let capacity = input.lines().count();
-
-        let mut nodes = Vec::with_capacity(capacity);
-        for line in input.lines() {
-            let squares: Result<Vec<Square>, _> = line.chars().map(|c| c.try_into()).collect();
-            nodes.push(squares?)
-        }
-
-        Ok(Grid { nodes })
+    fn from_input(input: &str) -> Result<Self, MapError> {
+        let grid: Grid<Square> = input.parse()?;
+        debug!(columns = grid.num_columns(), rows = grid.num_rows(), "map");
+        Ok(Map { grid })
     }
 
     // the grid extends infinitely to the right
     fn get_coordinates(&self, x: usize, y: usize) -> Option<&Square> {
-        self.nodes.get(y).and_then(|row| row.get(x % row.len()))
+        self.grid.get(x % self.grid.num_columns(), y)
     }
 
     // this iterator yields infinite amount of Squares if y = 0
     // starting point is always square at 0,0
-    fn slope(&self, x: usize, y: usize) -> SlopeIterator {
+    fn slope(&self, x: usize, y: usize) -> SlopeIterator<'_> {
         SlopeIterator {
-            grid: self,
+            map: self,
             x,
             y,
             iteration: 0,
@@ -101,12 +101,14 @@ impl Grid {
     }
 
     fn count_trees_in_slope(&self, x: usize, y: usize) -> usize {
-        self.slope(x, y).filter(|c| c.is_tree()).count()
+        let trees = self.slope(x, y).filter(|c| c.is_tree()).count();
+        debug!(right = x, down = y, trees, "slope");
+        trees
     }
 }
 
 struct SlopeIterator<'a> {
-    grid: &'a Grid,
+    map: &'a Map,
     x: usize,
     y: usize,
     iteration: usize,
@@ -116,7 +118,7 @@ impl<'a> Iterator for SlopeIterator<'a> {
     type Item = Square;
     fn next(&mut self) -> Option<Self::Item> {
         let next = self
-            .grid
+            .map
             .get_coordinates(self.x * self.iteration, self.y * self.iteration)
             .copied();
         self.iteration += 1;
@@ -125,37 +127,48 @@ impl<'a> Iterator for SlopeIterator<'a> {
 }
 
 #[derive(Error, Debug)]
-pub enum GridError {
-    #[error("could not parse valid square from `{0}`")]
-    ParseSquare(char),
+pub enum MapError {
+    #[error("invalid map, {0}")]
+    Parse(#[from] ParseGridError<UnknownSquare>),
 }
 
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+#[error("could not parse valid square from `{0}`")]
+pub struct UnknownSquare(char);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use helpers::read_file;
 
     #[test]
     fn test_parse() {
         let input = ".#.\n#.#";
 
-        let expected_nodes = vec![
-            vec![Square::Empty, Square::Tree, Square::Empty],
-            vec![Square::Tree, Square::Empty, Square::Tree],
+        let squares = vec![
+            Square::Empty,
+            Square::Tree,
+            Square::Empty,
+            Square::Tree,
+            Square::Empty,
+            Square::Tree,
         ];
-        let expected = Grid {
-            nodes: expected_nodes,
+        let expected = Map {
+            grid: Grid::with_items(squares, 3).unwrap(),
         };
-        let parsed = Grid::from_input(input).unwrap();
+        let parsed = Map::from_input(input).unwrap();
         assert_eq!(expected, parsed);
 
         let input = "asd#.f";
-        assert!(Grid::from_input(input).is_err())
+        assert!(Map::from_input(input).is_err());
+        let input = ".#.\n#.";
+        assert!(Map::from_input(input).is_err())
     }
 
     #[test]
     fn test_slope() {
         let input = "#....\n#....\n#....\n#....\n#...#";
-        let parsed = Grid::from_input(input).unwrap();
+        let parsed = Map::from_input(input).unwrap();
 
         let squares: Vec<_> = parsed.slope(0, 1).collect();
         assert_eq!(vec![Square::Tree; 5], squares);
@@ -180,9 +193,9 @@ mod tests {
     #[test]
     fn test_day1_example() {
         let input = read_file("../assets/days/day3_example.txt").unwrap();
-        let grid = Grid::from_input(&input).unwrap();
+        let map = Map::from_input(&input).unwrap();
 
-        let trees_encountered = grid.count_trees_in_slope(3, 1);
+        let trees_encountered = map.count_trees_in_slope(3, 1);
         assert_eq!(7, trees_encountered);
     }
 
@@ -197,11 +210,11 @@ mod tests {
     #[test]
     fn test_day2_example() {
         let input = read_file("../assets/days/day3_example.txt").unwrap();
-        let grid = Grid::from_input(&input).unwrap();
+        let map = Map::from_input(&input).unwrap();
 
         let trees_encountered: Vec<_> = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
             .iter()
-            .map(|(x, y)| grid.count_trees_in_slope(*x, *y))
+            .map(|(x, y)| map.count_trees_in_slope(*x, *y))
             .collect();
 
         // In the above example, these slopes would find 2, 7, 3, 4, and 2 tree(s) respectively;
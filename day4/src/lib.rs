@@ -1,58 +1,79 @@
-use crate::PassportError::MissingTokenPart;
 use crate::Token::{BirthYear, CountryID, ExpirationYear, IssueYear};
-use helpers::read_file;
+use helpers::parser::{
+    alt, blocks, key_value, literal, separated, spanned, take_while1, PResult, SyntaxError,
+};
+use helpers::{parser, ParseError, Solution};
 use std::convert::{TryFrom, TryInto};
-use std::error::Error;
 use thiserror::Error;
+use tracing::{debug, trace};
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = read_file("assets/days/day4.txt")?;
-    let passports = parse_passports(&input)?;
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input<'a> = Vec<Passport<'a>>;
+    type Error = ParseError<PassportError>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        parse_passports(input)
+    }
 
     // Part 1
     // Count the number of valid passports - those that have all required fields.
     // Treat cid as optional. In your batch file, how many passports are valid?
-    println!(
-        "Valid passports ignoring Country ID {}",
-        passports
+    fn part1(passports: &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        Ok(passports
             .iter()
             .filter(|p| p.has_all_fields_ignore_cid())
-            .count()
-    );
+            .count())
+    }
 
     // Part 2
     // Your job is to count the passports where all required fields are both present and valid
     // according to the given rules. Here are some example values:
-    println!(
-        "Valid passports with day2 rules {}",
-        passports
+    fn part2(passports: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        Ok(passports
             .iter()
-            .filter(|p| p.is_satisfying_rules_ignore_cid())
-            .count()
-    );
-
-    Ok(())
+            .filter(|p| {
+                let valid = p.is_satisfying_rules_ignore_cid();
+                trace!(passport = ?p, valid, "rules");
+                valid
+            })
+            .count())
+    }
 }
 
 // Each passport is represented as a sequence of key:value pairs separated by spaces or newlines. Passports are separated by blank lines.
-fn parse_passports(input: &str) -> Result<Vec<Passport>, PassportError> {
-    let mut passports = Vec::new();
-    let mut passport = Passport::default();
-    for x in input.lines().flat_map(|word| word.split(' ')) {
-        if !x.is_empty() {
-            passport.set(x.try_into()?);
-        } else {
-            passports.push(passport);
-            passport = Passport::default();
-        }
-    }
+fn parse_passports(input: &str) -> Result<Vec<Passport<'_>>, ParseError<PassportError>> {
+    let passports = parser::parse(input, blocks(fields)).map_err(|e| e.map(PassportError::from))?;
+    debug!(passports = passports.len(), "passports");
+    passports
+        .into_iter()
+        .map(|fields| {
+            let mut passport = Passport::default();
+            for (field, span) in fields {
+                let token = field
+                    .try_into()
+                    .map_err(|e| ParseError::at(input, span, e))?;
+                passport.set(token);
+            }
+            Ok(passport)
+        })
+        .collect()
+}
 
-    // push last passport if not empty
-    if passport != Passport::default() {
-        passports.push(passport);
-    }
+// key and value together with the whole field
+type Field<'a> = ((&'a str, &'a str), &'a str);
 
-    Ok(passports)
+// ecl:gry pid:860033327
+// hcl:#fffffd
+fn fields(input: &str) -> PResult<'_, Vec<Field<'_>>> {
+    let key = take_while1(|c: char| !c.is_whitespace() && c != ':', "a key");
+    let value = take_while1(|c: char| !c.is_whitespace(), "a value");
+    let field = spanned(key_value(key, value));
+    separated(field, alt(literal(" "), literal("\n")))(input)
 }
 
 type Year = u16;
@@ -69,22 +90,14 @@ enum Token<'a> {
     CountryID(&'a str),
 }
 
-impl<'a> TryFrom<&'a str> for Token<'a> {
+impl<'a> TryFrom<(&'a str, &'a str)> for Token<'a> {
     type Error = PassportError;
 
-    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        let mut parts = value.splitn(2, ':');
-        let key = parts
-            .next()
-            .ok_or_else(|| MissingTokenPart(value.to_string()))?;
-        let value = parts
-            .next()
-            .ok_or_else(|| MissingTokenPart(value.to_string()))?;
-
-        let token = match key {
-            "byr" => Ok(BirthYear(value.parse().unwrap())),
-            "iyr" => Ok(IssueYear(value.parse().unwrap())),
-            "eyr" => Ok(ExpirationYear(value.parse().unwrap())),
+    fn try_from((key, value): (&'a str, &'a str)) -> Result<Self, Self::Error> {
+        match key {
+            "byr" => Ok(BirthYear(parse_year(value)?)),
+            "iyr" => Ok(IssueYear(parse_year(value)?)),
+            "eyr" => Ok(ExpirationYear(parse_year(value)?)),
             "hgt" => Ok(Token::Height(value.into())),
             "hcl" => Ok(Token::HairColor(value.into())),
             "ecl" => Ok(Token::EyeColor(value.into())),
@@ -94,16 +107,22 @@ impl<'a> TryFrom<&'a str> for Token<'a> {
                 key.to_string(),
                 value.to_string(),
             )),
-        };
-
-        Ok(token?)
+        }
     }
 }
 
+fn parse_year(value: &str) -> Result<Year, PassportError> {
+    value
+        .parse()
+        .map_err(|_| PassportError::InvalidYear(value.to_string()))
+}
+
 #[derive(Error, Debug)]
 pub enum PassportError {
-    #[error("missing part of token in `{0}`")]
-    MissingTokenPart(String),
+    #[error("`{0}` is not a year")]
+    InvalidYear(String),
+    #[error("{0}")]
+    Syntax(#[from] SyntaxError),
     #[error("unknown token key {0} in `{1}`")]
     UnknownTokenKey(String, String),
 }
@@ -118,16 +137,15 @@ enum Height<'a> {
 //     hgt (Height) - a number followed by either cm or in
 impl<'a> From<&'a str> for Height<'a> {
     fn from(value: &'a str) -> Self {
-        let suffix = &value[value.len() - 2..];
-
-        value[..value.len() - 2]
-            .parse::<u16>()
-            .map(|prefix| match suffix {
-                "in" => Height::In(prefix),
-                "cm" => Height::Cm(prefix),
-                _ => Height::Unknown(value),
-            })
-            .unwrap_or(Height::Unknown(value))
+        let number = |suffix| value.strip_suffix(suffix)?.parse::<u16>().ok();
+
+        if let Some(inch) = number("in") {
+            Height::In(inch)
+        } else if let Some(cm) = number("cm") {
+            Height::Cm(cm)
+        } else {
+            Height::Unknown(value)
+        }
     }
 }
 
@@ -231,7 +249,7 @@ impl<'a> PassportID<'a> {
 //     pid (Passport ID)
 //     cid (Country ID)
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
-struct Passport<'a> {
+pub struct Passport<'a> {
     // Birth Year
     byr: Option<Year>,
     // Issue Year
@@ -289,28 +307,29 @@ impl<'a> Passport<'a> {
         //     hgt (Height)
         //         If cm, the number must be at least 150 and at most 193.
         //         If in, the number must be at least 59 and at most 76.
-        && self.hgt.map_or(false, |height|match height {
+        && self.hgt.is_some_and(|height|match height {
             Height::Cm(cm) => (150..=193).contains(&cm),
             Height::In(inch) => (59..=76).contains(&inch),
             Height::Unknown(_) => false
         })
         //     hcl (Hair Color)
-        && self.hcl.map_or(false, |hcl|hcl.is_valid())
+        && self.hcl.is_some_and(|hcl|hcl.is_valid())
         //     ecl (Eye Color)
-        && self.ecl.map_or(false, |ecl|ecl.is_valid())
+        && self.ecl.is_some_and(|ecl|ecl.is_valid())
         //     pid (Passport ID) - a nine-digit number, including leading zeroes.
-        && self.pid.map_or(false, |pid|pid.is_valid())
+        && self.pid.is_some_and(|pid|pid.is_valid())
         //     cid (Country ID) - ignored, missing or not.
     }
 }
 
 fn is_year_between(year: Option<Year>, min: Year, max: Year) -> bool {
-    year.map_or(false, |y| (min..=max).contains(&y))
+    year.is_some_and(|y| (min..=max).contains(&y))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use helpers::read_file;
 
     #[test]
     fn test_day1_example() {
@@ -351,6 +370,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_errors() {
+        let error =
+            parse_passports("ecl:gry pid:860033327\nhcl:#fffffd byr:19x7 iyr:2017").unwrap_err();
+        assert_eq!((2, 13, 8), (error.line, error.column, error.len));
+        assert!(matches!(error.error, PassportError::InvalidYear(_)));
+
+        let error = parse_passports("ecl:gry\n\nfoo:bar").unwrap_err();
+        assert_eq!((3, 1), (error.line, error.column));
+
+        let error = parse_passports("ecl:gry pid\n\nfoo:bar").unwrap_err();
+        assert_eq!((1, 12), (error.line, error.column));
+        assert!(matches!(
+            error.error,
+            PassportError::Syntax(SyntaxError::Literal(":"))
+        ));
+
+        let height: Height = "n".into();
+        assert_eq!(Height::Unknown("n"), height);
+    }
+
     #[test]
     fn test_parse_height() {
         let height: Height = "60in".into();
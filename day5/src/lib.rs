@@ -1,45 +1,54 @@
 use crate::TicketError::NoSeatFound;
-use helpers::read_file;
+use helpers::{parse_lines, ParseError, Solution};
 use itertools::Itertools;
 use std::convert::TryFrom;
-use std::error::Error;
 use thiserror::Error;
+use tracing::debug;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = read_file("./assets/days/day5.txt")?;
-    let mut tickets = input
-        .lines()
-        .map(BoardingPass::try_from)
-        .collect::<Result<Vec<_>, _>>()?;
-    tickets.sort_by_key(|t| t.seat_id);
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    // sorted by seat id
+    type Input<'a> = Vec<BoardingPass<'a>>;
+    type Error = TicketError;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        let mut tickets = parse_lines(input, BoardingPass::try_from)?;
+        tickets.sort_by_key(|t| t.seat_id);
+        debug!(tickets = tickets.len(), "boarding passes");
+        Ok(tickets)
+    }
 
     // Part 1:  What is the highest seat ID on a boarding pass?
-    let highest = tickets.last().ok_or(NoSeatFound)?.seat_id;
-    println!("Highest encountered seat id: {:?}", highest);
+    fn part1(tickets: &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        let highest = tickets.last().ok_or(NoSeatFound)?.seat_id;
+        Ok(highest)
+    }
 
     // Part 2: Your seat wasn't at the very front or back, though;
     // the seats with IDs +1 and -1 from yours will be in your list.
     // What is the ID of your seat?
-    let (before, after) = tickets
-        .iter()
-        .tuple_windows::<(_, _)>()
-        .find(|(a, b)| a.seat_id + 1 != b.seat_id)
-        .ok_or(NoSeatFound)?;
-    println!(
-        "Empty Seat Id {} between {:?} and {:?}!",
-        before.seat_id + 1,
-        before,
-        after
-    );
-
-    Ok(())
+    fn part2(tickets: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        let (before, _) = tickets
+            .iter()
+            .tuple_windows::<(_, _)>()
+            .find(|(a, b)| a.seat_id + 1 != b.seat_id)
+            .ok_or(NoSeatFound)?;
+        debug!(seat = before.seat_id, row = before.row_id, "seat before the gap");
+        Ok(before.seat_id + 1)
+    }
 }
 
 static _PLANE_ROWS: usize = 128;
 static PLANE_COLUMNS: usize = 8;
 
+// the decoded row and column are only kept for debug output
+#[allow(dead_code)]
 #[derive(Clone, Debug)]
-struct BoardingPass<'a> {
+pub struct BoardingPass<'a> {
     row_input: &'a str,
     col_input: &'a str,
     row_id: usize,
@@ -56,19 +65,25 @@ impl<'a> BoardingPass<'a> {
 
 #[derive(Error, Debug)]
 pub enum TicketError {
+    #[error("could not parse boarding pass, {0}")]
+    Parse(#[from] ParseError<SeatNumberError>),
+    #[error("Boarding error, Seat Row {0}, column {1} does not exist!")]
+    BoardingError(usize, usize),
+    #[error("no Seat found")]
+    NoSeatFound,
+}
+
+#[derive(Error, Debug)]
+pub enum SeatNumberError {
     #[error("unexpected token `{0}` in SeatNumber `{1}`")]
     UnexpectedToken(String, String),
     #[error("SeatNumber {0} should be exactly 10 characters long")]
     UnexpectedLength(String),
     #[error("Row Identifiers {0} should only contain '{1}' or '{2}'")]
     UnexpectedBinaryToken(String, char, char),
-    #[error("Boarding error, Seat Row {0}, column {1} does not exist!")]
-    BoardingError(usize, usize),
-    #[error("no Seat found")]
-    NoSeatFound,
 }
 
-fn binary_partition(input: &str, high: char, low: char) -> Result<usize, TicketError> {
+fn binary_partition(input: &str, high: char, low: char) -> Result<usize, SeatNumberError> {
     let mut value = 0;
     for (i, c) in input.chars().rev().enumerate() {
         if c == low {
@@ -76,7 +91,7 @@ fn binary_partition(input: &str, high: char, low: char) -> Result<usize, TicketE
         } else if c == high {
             value += 2_usize.pow(i as u32)
         } else {
-            return Err(TicketError::UnexpectedBinaryToken(
+            return Err(SeatNumberError::UnexpectedBinaryToken(
                 input.to_string(),
                 high,
                 low,
@@ -90,19 +105,19 @@ fn binary_partition(input: &str, high: char, low: char) -> Result<usize, TicketE
 // A seat might be specified like FBFBBFFRLR, where
 // F means "front", B means "back", L means "left", and R means "right".
 impl<'a> TryFrom<&'a str> for BoardingPass<'a> {
-    type Error = TicketError;
+    type Error = SeatNumberError;
 
     fn try_from(s: &'a str) -> Result<Self, Self::Error> {
         // 7 row chars + 3 col chars
         if s.chars().count() != 10 {
-            return Err(TicketError::UnexpectedLength(s.to_owned()));
+            return Err(SeatNumberError::UnexpectedLength(s.to_owned()));
         }
 
         // The first 7 characters will either be F or B; these specify exactly one of the 128
         // rows on the plane (numbered 0 through 127)
         // The last three characters will be either L or R; these specify exactly one of the 8
         // columns of seats on the plane (numbered 0 through 7).
-        let (row, col) = s.split_at(7);
+        let (row, col) = s.split_at(s.char_indices().nth(7).map_or(0, |(i, _)| i));
         let row_id = binary_partition(row, 'B', 'F')?;
         let col_id = binary_partition(col, 'R', 'L')?;
 
@@ -154,6 +169,22 @@ mod tests {
         assert_eq!(5, binary_partition("RLR", 'R', 'L').unwrap());
     }
 
+    #[test]
+    fn test_parse_errors() {
+        let error = Day5::parse("FBFBBFFRLR\nFBFBBFFRL").unwrap_err();
+        assert!(matches!(
+            error,
+            TicketError::Parse(ParseError {
+                line: 2,
+                error: SeatNumberError::UnexpectedLength(_),
+                ..
+            })
+        ));
+        assert!(BoardingPass::try_from("FBFBBFXRLR").is_err());
+        assert!(BoardingPass::try_from("FBFBBFFRLÄ").is_err());
+        assert!(BoardingPass::try_from("FBFBBFÄRLR").is_err());
+    }
+
     #[test]
     fn test_compute_seat_id() {
         // In this example, the seat has ID 44 * 8 + 5 = 357
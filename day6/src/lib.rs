@@ -0,0 +1,61 @@
+use helpers::Solution;
+use std::convert::Infallible;
+use std::ops::RangeInclusive;
+use tracing::{debug, trace};
+
+// The form asks a series of 26 yes-or-no questions marked 'a' through 'z'.
+const POSSIBLE_ANSWERS: RangeInclusive<char> = 'a'..='z';
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input<'a> = Vec<&'a str>;
+    type Error = Infallible;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    // Each group's answers are separated by a blank line, and within each group,
+    // each person's answers are on a single line.
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        let groups: Vec<_> = input.split("\n\n").collect();
+        debug!(groups = groups.len(), "groups");
+        Ok(groups)
+    }
+
+    // Part 1
+    // All you need to do is identify the questions for which anyone in your group answers "yes".
+    fn part1(groups: &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        let sum = groups
+            .iter()
+            .map(|group| {
+                let count = POSSIBLE_ANSWERS
+                    .filter(|answer| group.lines().any(|form| form.contains(*answer)))
+                    // For each group, count the number of questions to which anyone answered "yes".
+                    // What is the sum of those counts?
+                    .count();
+                trace!(count, "group");
+                count
+            })
+            .sum();
+        Ok(sum)
+    }
+
+    // Part 2
+    // you need to identify the questions to which everyone answered "yes"!
+    fn part2(groups: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        let sum = groups
+            .iter()
+            .map(|group| {
+                let count = POSSIBLE_ANSWERS
+                    .filter(|answer| group.lines().all(|form| form.contains(*answer)))
+                    // For each group, count the number of questions to which everyone answered "yes".
+                    // What is the sum of those counts?
+                    .count();
+                trace!(count, "group");
+                count
+            })
+            .sum();
+        Ok(sum)
+    }
+}
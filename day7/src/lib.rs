@@ -1,32 +1,44 @@
-use helpers::read_file;
+use helpers::parser::{
+    alt, integer, lines, literal, map, separated, separated_pair, take_until, terminated, PResult,
+    SyntaxError,
+};
+use helpers::{parser, ParseError, Solution};
 use std::collections::HashMap;
-use std::error::Error;
 use thiserror::Error;
+use tracing::{debug, trace};
 
 static SHINY_GOLD: &str = "shiny gold";
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = read_file("./assets/days/day7.txt")?;
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input<'a> = Rules<'a>;
+    type Error = BagError;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        parse_rules(input)
+    }
 
-    let rules = parse_rules(&input)?;
     // Part 1: How many bag colors can eventually contain at least one shiny gold bag?
-    println!(
-        "{} bags eventually contain any gold bags.",
-        rules.count_can_reach_color(SHINY_GOLD)
-    );
+    fn part1(rules: &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        Ok(rules.count_can_reach_color(SHINY_GOLD))
+    }
 
     // Part 2: How many individual bags are required inside your single shiny gold bag?
-    println!(
-        "{} bags inside a single gold bag.",
-        rules.count_contained_bags(SHINY_GOLD)?
-    );
-
-    Ok(())
+    fn part2(rules: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        rules.count_contained_bags(SHINY_GOLD)
+    }
 }
 
+// count and color of the bags inside a bag
+type Contents<'a> = Vec<(usize, &'a str)>;
+
 #[derive(Clone, Debug)]
-struct Rules<'a> {
-    rules: HashMap<&'a str, Vec<(usize, &'a str)>>,
+pub struct Rules<'a> {
+    rules: HashMap<&'a str, Contents<'a>>,
 }
 
 impl<'a> Rules<'a> {
@@ -39,7 +51,7 @@ impl<'a> Rules<'a> {
     }
     fn can_reach(&self, start: &str, target: &str) -> bool {
         // unknown start items count as not reachable
-        self.rules.get(start).map_or(false, |children| {
+        self.rules.get(start).is_some_and(|children| {
             children
                 .iter()
                 // based on lazy evaluation, either target is a reachable, or continue searching
@@ -65,105 +77,108 @@ impl<'a> Rules<'a> {
         {
             sum += count * self.count_bags(color)?
         }
+        trace!(color, bags = sum, "nested bags");
         Ok(sum)
     }
 }
 
-fn parse_rules(input: &str) -> Result<Rules, BagError> {
-    let mut rules: HashMap<&str, Vec<(usize, &str)>> = HashMap::new();
-    for definition in input.lines().map(|line| line.trim_end_matches('.')) {
-        let split_at = definition
-            .find(SEPARATOR)
-            .ok_or_else(|| BagError::SplitParts(definition.to_string()))?;
-        let (color, suffix) = definition.split_at(split_at);
-        let content = suffix.split_at(SEPARATOR_LEN).1;
-        let bags = parse_content(content)?;
+fn parse_rules(input: &str) -> Result<Rules<'_>, BagError> {
+    let mut rules: HashMap<&str, Contents> = HashMap::new();
+    let parsed = parser::parse(input, lines(rule)).map_err(|e| e.map(RuleError::from))?;
+    for (color, bags) in parsed {
         // if there is already an entry there went something wrong
         if rules.insert(color, bags).is_some() {
-            return Err(BagError::DuplicateRule(color.to_string()));
+            let error = RuleError::DuplicateRule(color.to_string());
+            return Err(ParseError::at(input, color, error).into());
         }
     }
 
+    debug!(rules = rules.len(), "rules");
     Ok(Rules { rules })
 }
 
 static SEPARATOR: &str = " bags contain ";
-static SEPARATOR_LEN: usize = 14;
-
-static SINGULAR_SUFFIX: &str = " bag";
-static PLURAL_SUFFIX: &str = " bags";
-
-fn parse_content(content: &str) -> Result<Vec<(usize, &str)>, BagError> {
-    let mut bags = Vec::new();
-    if content == ("no other bags") {
-        return Ok(bags);
-    };
-
-    for bag in content.split(", ") {
-        let bag = remove_bag_suffix(bag)?;
-        // split count and color
-        let (count, color) = bag
-            .find(' ')
-            .map(|mid| {
-                let (count, color) = bag.split_at(mid);
-                // remove leading space
-                let color = color.trim_start();
-                (count, color)
-            })
-            .ok_or_else(|| BagError::SplitContent(bag.to_string()))?;
-        let count: usize = count
-            .parse()
-            .map_err(|_| BagError::ParseCount(count.to_string()))?;
-        bags.push((count, color));
-    }
-    Ok(bags)
+
+// light red bags contain 1 bright white bag, 2 muted yellow bags.
+fn rule(input: &str) -> PResult<'_, (&str, Contents<'_>)> {
+    let color = take_until(SEPARATOR);
+    let rule = separated_pair(color, literal(SEPARATOR), contents);
+    terminated(rule, literal("."))(input)
 }
 
-fn remove_bag_suffix(value: &str) -> Result<&str, BagError> {
-    if value.ends_with(PLURAL_SUFFIX) {
-        Ok(value.trim_end_matches(PLURAL_SUFFIX))
-    } else if value.ends_with(SINGULAR_SUFFIX) {
-        Ok(value.trim_end_matches(SINGULAR_SUFFIX))
-    } else {
-        Err(BagError::UnknownBagSuffix(value.to_string()))
-    }
+// no other bags
+// 1 bright white bag, 2 muted yellow bags
+fn contents(input: &str) -> PResult<'_, Contents<'_>> {
+    let no_bags = map(literal("no other bags"), |_| Vec::new());
+    let bag = separated_pair(integer(), literal(" "), bag_color);
+    alt(no_bags, separated(bag, literal(", ")))(input)
+}
+
+// the color in front of ` bag` or ` bags`
+fn bag_color(input: &str) -> PResult<'_, &str> {
+    let suffix = alt(literal(" bags"), literal(" bag"));
+    terminated(take_until(" bag"), suffix)(input)
 }
 
 #[derive(Error, Clone, Debug)]
-enum BagError {
-    #[error("could not split {0} at ' bags contain '")]
-    SplitParts(String),
-    #[error("could not bag contents {0} at ', '")]
-    SplitContent(String),
-    #[error("could get bag count from {0}")]
-    ParseCount(String),
-    #[error("duplicate rule for {0}")]
-    DuplicateRule(String),
-    #[error("unknown suffix for {0}, only ' bags' and ' bag' are allowed")]
-    UnknownBagSuffix(String),
+pub enum BagError {
+    #[error("could not parse rules, {0}")]
+    Parse(#[from] ParseError<RuleError>),
     #[error("unknown bag color {0}")]
     UnknownBagColor(String),
 }
 
+#[derive(Error, Clone, Debug)]
+pub enum RuleError {
+    #[error("{0}")]
+    Syntax(#[from] SyntaxError),
+    #[error("duplicate rule for {0}")]
+    DuplicateRule(String),
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use helpers::read_file;
 
     #[test]
     fn test_parse_suffix() {
         let expected = "light brown";
 
-        let singular = remove_bag_suffix("light brown bag");
-        let plural = remove_bag_suffix("light brown bags");
-        let missing_space = remove_bag_suffix("light brownbags");
-        let wrong_suffix = remove_bag_suffix("light brown gags");
+        let singular = bag_color("light brown bag");
+        let plural = bag_color("light brown bags, 2 dark red bags.");
+        let missing_space = bag_color("light brownbags");
+        let wrong_suffix = bag_color("light brown gags");
 
-        assert_eq!(expected, singular.unwrap());
-        assert_eq!(expected, plural.unwrap());
+        assert_eq!(Ok((expected, "")), singular);
+        assert_eq!(Ok((expected, ", 2 dark red bags.")), plural);
         assert!(missing_space.is_err());
         assert!(wrong_suffix.is_err());
     }
 
+    #[test]
+    fn test_parse_errors() {
+        let input =
+            "faded blue bags contain no other bags.\ndotted black bags contain 2 faded blue.";
+        let error = parse_rules(input).unwrap_err();
+        assert!(matches!(
+            error,
+            BagError::Parse(ParseError {
+                line: 2,
+                column: 29,
+                error: RuleError::Syntax(SyntaxError::Literal(" bag")),
+                ..
+            })
+        ));
+
+        let input =
+            "faded blue bags contain no other bags.\nfaded blue bags contain no other bags.";
+        match parse_rules(input).unwrap_err() {
+            BagError::Parse(error) => assert_eq!((2, 1, 10), (error.line, error.column, error.len)),
+            error => panic!("unexpected error {}", error),
+        }
+    }
+
     // In the above rules, the following options would be available to you:
     //
     //     A bright white bag, which can hold your shiny gold bag directly.
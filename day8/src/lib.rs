@@ -1,48 +1,60 @@
 use crate::Op::{Acc, Jmp, Nop};
-use helpers::read_file;
+use helpers::{parse_lines, Detailed, ParseError, Solution};
 use std::collections::HashSet;
-use std::error::Error;
+use std::convert::TryFrom;
 use std::num::ParseIntError;
 use std::str::FromStr;
 use thiserror::Error;
+use tracing::{debug, trace};
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let input = read_file("./assets/days/day8.txt")?;
-    let program = BootCode::from_str(&input)?;
+pub struct Day8;
 
-    // Day 1
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input<'a> = BootCode;
+    type Error = BootCodeError;
+    type Part1 = Data;
+    type Part2 = Detailed<Data>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        BootCode::from_str(input)
+    }
+
+    // Part 1
     // Run your copy of the boot code.
     // Immediately before any instruction is executed a second time, what value is in the accumulator?
-    let mut day1 = program.clone();
-    day1.run_until_loop()?;
-    println!(
-        "Accumulator value before running the same instruction twice: {}",
-        day1.accumulator
-    );
-
-    // Day 2
+    fn part1(program: &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        let mut program = program.clone();
+        match program.run_until_loop()? {
+            ExitStatus::OutOfProgram => {
+                Err(BootCodeError::JumpOutOfProgram(program.instruction_pointer))
+            }
+            _ => Ok(program.accumulator),
+        }
+    }
+
+    // Part 2
     // Fix the program so that it terminates normally by changing exactly one jmp (to nop) or
     // nop (to jmp). What is the value of the accumulator after the program terminates?
-    for (idx, &instruction) in program.instructions.iter().enumerate() {
-        let substitute = match instruction {
-            Acc(_) => continue,
-            Jmp(v) => Nop(v),
-            Nop(v) => Jmp(v),
-        };
+    fn part2(program: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        for (idx, &instruction) in program.instructions.iter().enumerate() {
+            let substitute = match instruction {
+                Acc(_) => continue,
+                Jmp(v) => Nop(v),
+                Nop(v) => Jmp(v),
+            };
 
-        // Check if modified program terminates
-        let mut program = program.clone();
-        program.instructions[idx] = substitute;
-        if ExitStatus::Terminated == program.run_until_loop()? {
-            println!(
-                "Accumulator value when terminating program, substituting op {}: {}",
-                idx, program.accumulator
-            );
-            break
+            // Check if modified program terminates
+            let mut program = program.clone();
+            program.instructions[idx] = substitute;
+            if ExitStatus::Terminated == program.run_until_loop()? {
+                debug!(index = idx, ?substitute, "terminating substitution");
+                return Ok(Detailed::new(program.accumulator).with("substituted_index", idx));
+            }
         }
-    }
 
-    Ok(())
+        Err(BootCodeError::NoTerminatingSubstitution)
+    }
 }
 
 // Each instruction consists of an operation (acc, jmp, or nop) and an argument (a signed number like +4 or -20).
@@ -60,26 +72,24 @@ enum Op {
 // acc +1
 // jmp -4
 impl FromStr for Op {
-    type Err = BootCodeError;
+    type Err = InstructionError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (op, arg) = s.split_at(3);
-        if !arg.starts_with(' ') {
-            return Err(BootCodeError::ParseOp(s.to_string()));
-        }
-        // skip leading space
-        let arg = arg[1..].parse()?;
+        let (op, arg) = s
+            .split_once(' ')
+            .ok_or_else(|| InstructionError::ParseOp(s.to_string()))?;
+        let arg = arg.parse()?;
         match op {
             "nop" => Ok(Nop(arg)),
             "jmp" => Ok(Jmp(arg)),
             "acc" => Ok(Acc(arg)),
-            _ => Err(BootCodeError::ParseOp(s.to_string())),
+            _ => Err(InstructionError::ParseOp(s.to_string())),
         }
     }
 }
 
 #[derive(Clone, Debug)]
-struct BootCode {
+pub struct BootCode {
     instruction_pointer: usize,
     accumulator: Data,
     instructions: Vec<Op>,
@@ -88,7 +98,9 @@ struct BootCode {
 #[derive(Copy,Clone,Debug, Eq, PartialEq)]
 enum ExitStatus {
     Terminated,
-    LoopDetected
+    LoopDetected,
+    // a jump left the program, other than to right after the last instruction
+    OutOfProgram,
 }
 
 impl BootCode {
@@ -104,8 +116,9 @@ impl BootCode {
         self.instructions[self.instruction_pointer]
     }
 
-    // returns true if program terminated
-    fn next_cycle(&mut self) -> Option<ExitStatus> {
+    // returns the exit status once the program stopped, the instruction pointer stays at a jump
+    // out of the program
+    fn next_cycle(&mut self) -> Result<Option<ExitStatus>, BootCodeError> {
         let op = self.fetch_instruction();
 
         let offset = match op {
@@ -114,7 +127,10 @@ impl BootCode {
             // The accumulator starts at 0.
             // After an acc instruction, the instruction immediately below it is executed next.
             Acc(v) => {
-                self.accumulator += v;
+                self.accumulator = self
+                    .accumulator
+                    .checked_add(v)
+                    .ok_or(BootCodeError::AccumulatorOverflow(self.instruction_pointer))?;
                 1
             }
             // jmp jumps to a new instruction relative to itself.
@@ -129,31 +145,37 @@ impl BootCode {
             Nop(_) => 1,
         };
 
-        if offset.is_positive() {
-            self.instruction_pointer += offset as usize
-        } else {
-            self.instruction_pointer -= offset.abs() as usize
-        }
+        let target = self.instruction_pointer as i64 + i64::from(offset);
+        let target = match usize::try_from(target) {
+            Ok(target) if target <= self.instructions.len() => target,
+            _ => return Ok(Some(ExitStatus::OutOfProgram)),
+        };
+        self.instruction_pointer = target;
 
         // Program terminates if the instruction pointer points to the one right after the last instruction
         if self.instruction_pointer == self.instructions.len() {
-            Some(ExitStatus::Terminated)
+            Ok(Some(ExitStatus::Terminated))
         }  else {
-            None
+            Ok(None)
         }
     }
 
     // true if the program terminates correctly
     // false if the instruction_pointer points to the same instruction twice
     fn run_until_loop(&mut self) -> Result<ExitStatus, BootCodeError> {
+        // an empty program has nothing to run
+        if self.instructions.is_empty() {
+            return Ok(ExitStatus::Terminated);
+        }
         let mut visited = HashSet::new();
         let mut index = 0_usize;
         loop {
             if visited.contains(&index) {
+                trace!(index, accumulator = self.accumulator, "loop");
                 break Ok(ExitStatus::LoopDetected);
             } else {
                 visited.insert(index);
-                if let Some(exit_status) = self.next_cycle() {
+                if let Some(exit_status) = self.next_cycle()? {
                     break Ok(exit_status);
                 }
                 index = self.instruction_pointer;
@@ -166,16 +188,26 @@ impl FromStr for BootCode {
     type Err = BootCodeError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let ops = s
-            .lines()
-            .map(|l| Op::from_str(l))
-            .collect::<Result<Vec<_>, _>>()?;
+        let ops = parse_lines(s, Op::from_str)?;
+        debug!(instructions = ops.len(), "boot code");
         Ok(BootCode::new(ops))
     }
 }
 
 #[derive(Error, Debug, Clone)]
-enum BootCodeError {
+pub enum BootCodeError {
+    #[error("could not parse boot code, {0}")]
+    Parse(#[from] ParseError<InstructionError>),
+    #[error("no single substitution makes the program terminate")]
+    NoTerminatingSubstitution,
+    #[error("instruction {0} jumps out of the program")]
+    JumpOutOfProgram(usize),
+    #[error("the accumulator overflows at instruction {0}")]
+    AccumulatorOverflow(usize),
+}
+
+#[derive(Error, Debug, Clone)]
+pub enum InstructionError {
     #[error("invalid op '{0}'")]
     ParseOp(String),
     #[error("could not parse op argument")]
@@ -210,6 +242,43 @@ mod tests {
         assert_eq!(expected, ops);
     }
 
+    #[test]
+    fn test_parse_errors() {
+        for line in &["nop", "nop+0", "mul +2", "jmp +x", "ü"] {
+            assert!(line.parse::<Op>().is_err(), "{}", line);
+        }
+
+        let error = BootCode::from_str("nop +0\nacc 1x\njmp -2").unwrap_err();
+        assert!(matches!(
+            error,
+            BootCodeError::Parse(ParseError {
+                line: 2,
+                error: InstructionError::ParseArg(_),
+                ..
+            })
+        ));
+    }
+
+    // running these programs used to panic, e.g. the empty program found by fuzzing
+    #[test]
+    fn test_malformed_programs() {
+        let part1 = |input| Day8::part1(&Day8::parse(input).unwrap());
+        assert!(matches!(part1("nop +0\njmp -2"), Err(BootCodeError::JumpOutOfProgram(1))));
+        assert!(matches!(part1("jmp +3\nacc +1"), Err(BootCodeError::JumpOutOfProgram(0))));
+        assert!(matches!(
+            part1("acc +2147483647\nacc +1"),
+            Err(BootCodeError::AccumulatorOverflow(1))
+        ));
+        assert_eq!(0, part1("").unwrap());
+
+        // the substituted jmp leaves the program
+        let program = Day8::parse("nop +5").unwrap();
+        assert!(matches!(
+            Day8::part2(&program),
+            Err(BootCodeError::NoTerminatingSubstitution)
+        ));
+    }
+
     // For example, consider the following program:
     // These instructions are visited in this order:
     //
@@ -240,7 +309,7 @@ mod tests {
         let visited_ops: Vec<_> = (0..8)
             .map(|_| {
                 let next = program.fetch_instruction();
-                program.next_cycle();
+                program.next_cycle().unwrap();
                 next
             })
             .collect();
@@ -258,4 +327,15 @@ mod tests {
 
         assert_eq!(program.accumulator, 5);
     }
+
+    // After changing the jmp -4 at index 7 to nop, the program terminates with an accumulator of 8
+    #[test]
+    fn test_example_part2_substitution() {
+        let input = read_file("../assets/days/day8_example.txt").unwrap();
+        let program = Day8::parse(&input).unwrap();
+
+        let answer = Day8::part2(&program).unwrap();
+        assert_eq!(8, answer.answer);
+        assert_eq!(vec![("substituted_index", "7".to_string())], answer.details);
+    }
 }
@@ -1,38 +1,63 @@
-use helpers::parse_lines_file;
+use helpers::{parse_lines, stream, ParseError, ReadError, Solution};
 use itertools::{Itertools, MinMaxResult};
-use std::error::Error;
+use std::num::ParseIntError;
 use thiserror::Error;
 use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::io::BufRead;
+use tracing::debug;
 
 type Data = u64;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let numbers : Vec<Data> = parse_lines_file("./assets/days/day9.txt")?;
+// The XMAS cypher uses a preamble of 25 numbers
+static PREAMBLE_SIZE: usize = 25;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input<'a> = Vec<Data>;
+    type Error = XMASCypherError;
+    type Part1 = Data;
+    type Part2 = Data;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        let numbers = parse_lines(input, str::parse)?;
+        debug!(numbers = numbers.len(), "data");
+        Ok(numbers)
+    }
 
-    let cypher = XMASCypher::new(&numbers);
     // Part 1
     // The first step of attacking the weakness in the XMAS data is to find the first number in the
     // list (after the preamble) which is not the sum of two of the 25 numbers before it.
     // What is the first number that does not have this property?
-    let first_invalid = cypher.find_first_invalid(25);
-    println!("First invalid number: {:?}", first_invalid);
+    fn part1(numbers: &Self::Input<'_>) -> Result<Self::Part1, Self::Error> {
+        let cypher = XMASCypher::new(numbers);
+        cypher
+            .find_first_invalid(PREAMBLE_SIZE)
+            .ok_or(XMASCypherError::NoInvalidNumber)
+    }
 
     // Part 2
     // The final step in breaking the XMAS encryption relies on the invalid number you just found:
-    if let Some(target_sum) = first_invalid {
+    fn part2(numbers: &Self::Input<'_>) -> Result<Self::Part2, Self::Error> {
+        let cypher = XMASCypher::new(numbers);
+        let target_sum = cypher
+            .find_first_invalid(PREAMBLE_SIZE)
+            .ok_or(XMASCypherError::NoInvalidNumber)?;
         // you must find a contiguous set of at least two numbers in your list which sum to the invalid
         // number from step 1.
         if let Some(subset) = cypher.find_contiguous_subset_with_sum(target_sum) {
+            debug!(target_sum, len = subset.len(), "contiguous set");
             // To find the encryption weakness, add together the smallest and largest number
             // in this contiguous range;
-            if let MinMaxResult::MinMax(&min,&max) = subset.iter().minmax() {
-                println!("Sum of smallest and largest number in subset {} + {} = {:?}", min, max, min + max);
-                return Ok(())
+            if let MinMaxResult::MinMax(&min, &max) = subset.iter().minmax() {
+                return Ok(min + max);
             }
         }
-    }
 
-    Err(XMASCypherError::Day2Error.into())
+        Err(XMASCypherError::Day2Error)
+    }
 }
 
 struct XMASCypherValidator {
@@ -52,11 +77,11 @@ impl<'a> XMASCypher<'a> {
     // find the first number in the list (after the preamble) which is not the sum of two of the
     // n (n=`preamble_size`) numbers before it
     fn find_first_invalid(&self, preamble_size: usize) -> Option<Data> {
-        let mut data = self.data.iter().copied();
-        let preamble: Vec<Data> = (&mut data).take(preamble_size).collect();
-        let mut cypher = XMASCypherValidator::with_preamble(preamble);
-
-        data.find(|v| cypher.step(*v) == Step::Invalid )
+        let data = self.data.iter().map(|&v| Ok::<_, Infallible>(v));
+        match find_first_invalid(data, preamble_size) {
+            Ok(invalid) => invalid,
+            Err(never) => match never {},
+        }
     }
 
     // find a contiguous set of at least two numbers in your list which sum to the given target
@@ -79,6 +104,33 @@ impl<'a> XMASCypher<'a> {
     }
 }
 
+// Part 1 read line by line, e.g. from a pipe, without holding the whole input in memory
+pub fn part1_from_reader<R: BufRead>(reader: R) -> Result<Data, XMASCypherError> {
+    find_first_invalid(stream::parsed_lines(reader), PREAMBLE_SIZE)?
+        .ok_or(XMASCypherError::NoInvalidNumber)
+}
+
+// Finds the first invalid number in a stream of numbers, e.g. read lazily with
+// `helpers::stream::parsed_lines`. Only the preamble is kept in memory, reading stops at the first
+// error.
+pub fn find_first_invalid<E>(
+    numbers: impl IntoIterator<Item = Result<Data, E>>,
+    preamble_size: usize,
+) -> Result<Option<Data>, E> {
+    let mut numbers = numbers.into_iter();
+    let preamble = (&mut numbers).take(preamble_size).collect::<Result<_, E>>()?;
+    let mut cypher = XMASCypherValidator::with_preamble(preamble);
+
+    for number in numbers {
+        let number = number?;
+        if cypher.step(number) == Step::Invalid {
+            debug!(number, "invalid");
+            return Ok(Some(number));
+        }
+    }
+    Ok(None)
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 enum Step {
     Valid,
@@ -113,6 +165,7 @@ impl XMASCypherValidator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use helpers::{parse_lines_file, stream};
     use itertools::MinMaxResult;
 
     static EXPECTED_INVALID: Data = 127;
@@ -151,10 +204,43 @@ mod tests {
             panic!("Could not get minimum and maximum numbers!");
         };
     }
+
+    #[test]
+    fn test_find_first_invalid_streaming() {
+        let file = stream::open("../assets/days/day9_example.txt").unwrap();
+        let first_invalid = find_first_invalid(stream::parsed_lines(file), 5).unwrap();
+        assert_eq!(Some(EXPECTED_INVALID), first_invalid);
+
+        // the numbers are read lazily, the broken line behind the invalid number is never read
+        let input = "1\n2\n3\n4\n5\n6\n100\nx";
+        let first_invalid = find_first_invalid(stream::parsed_lines(input.as_bytes()), 5);
+        assert_eq!(Some(100), first_invalid.unwrap());
+        let first_invalid = find_first_invalid(stream::parsed_lines::<_, Data>("1\nx".as_bytes()), 5);
+        assert!(first_invalid.is_err());
+    }
+
+    #[test]
+    fn test_part1_from_reader() {
+        // a full preamble of 25 numbers, followed by a valid and an invalid number
+        let input = (1..=25).chain(vec![26, 100]).join("\n");
+        assert_eq!(100, Day9::part1(&Day9::parse(&input).unwrap()).unwrap());
+        assert_eq!(100, part1_from_reader(input.as_bytes()).unwrap());
+
+        assert!(matches!(
+            part1_from_reader("1\nx".as_bytes()),
+            Err(XMASCypherError::ReadNumber(ReadError::Parse(_)))
+        ));
+    }
 }
 
-#[derive(Error, Debug, Clone)]
-enum XMASCypherError {
+#[derive(Error, Debug)]
+pub enum XMASCypherError {
+    #[error("could not parse number, {0}")]
+    ParseNumber(#[from] ParseError<ParseIntError>),
+    #[error(transparent)]
+    ReadNumber(#[from] ReadError<ParseIntError>),
+    #[error("every number is the sum of two of the numbers before it")]
+    NoInvalidNumber,
     #[error("day2 not run")]
     Day2Error,
 }
\ No newline at end of file
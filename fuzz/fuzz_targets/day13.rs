@@ -0,0 +1,10 @@
+#![no_main]
+use helpers::Solution;
+use libfuzzer_sys::fuzz_target;
+
+// Malformed inputs are errors, parsing never panics
+fuzz_target!(|input: &str| {
+    if let Err(e) = day13::Day13::parse(input) {
+        e.to_string();
+    }
+});
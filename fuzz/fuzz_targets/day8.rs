@@ -0,0 +1,16 @@
+#![no_main]
+use day8::Day8;
+use helpers::Solution;
+use libfuzzer_sys::fuzz_target;
+
+// Malformed inputs are errors, parsing and running the boot code never panics. The parts run every
+// instruction at most once per program, they stay fast on any input.
+fuzz_target!(|input: &str| match Day8::parse(input) {
+    Ok(program) => {
+        let _ = Day8::part1(&program);
+        let _ = Day8::part2(&program);
+    }
+    Err(e) => {
+        e.to_string();
+    }
+});
@@ -0,0 +1,566 @@
+use crate::grid::{Direction, Grid, NEIGHBOURS_4, NEIGHBOURS_8};
+use crate::hex::{Hex, HexDirection};
+use crate::sparse::{add, BoundingBox, Point, Points, SparseGrid};
+use std::mem;
+use std::slice;
+
+// The cells of a cellular automaton, e.g. a dense Grid or a SparseGrid
+pub trait Space: Clone + PartialEq {
+    type Position: Copy;
+    type Cell: PartialEq;
+
+    fn cell(&self, position: Self::Position) -> &Self::Cell;
+    fn set_cell(&mut self, position: Self::Position, cell: Self::Cell);
+    // Calls f with every position which may change in the next step
+    fn for_each_position<F: FnMut(Self::Position)>(&self, f: F);
+    // Prepares the buffer receiving the next step of self
+    fn reset(&self, next: &mut Self);
+}
+
+impl<T: Clone + PartialEq> Space for Grid<T> {
+    type Position = (usize, usize);
+    type Cell = T;
+
+    fn cell(&self, (col, row): Self::Position) -> &T {
+        &self.items()[row * self.num_columns() + col]
+    }
+
+    fn set_cell(&mut self, (col, row): Self::Position, cell: T) {
+        self.set(col, row, cell)
+    }
+
+    fn for_each_position<F: FnMut(Self::Position)>(&self, mut f: F) {
+        for row in 0..self.num_rows() {
+            for col in 0..self.num_columns() {
+                f((col, row))
+            }
+        }
+    }
+
+    // every cell of next is overwritten, it only needs the same size
+    fn reset(&self, next: &mut Self) {
+        if next.num_columns() != self.num_columns() || next.num_rows() != self.num_rows() {
+            *next = self.clone();
+        }
+    }
+}
+
+// Only the cells next to the stored ones are considered, so the rule has to keep a default cell
+// surrounded by default cells unchanged
+impl<T: Clone + PartialEq, const N: usize> Space for SparseGrid<T, N> {
+    type Position = [isize; N];
+    type Cell = T;
+
+    fn cell(&self, position: Self::Position) -> &T {
+        self.get(&position)
+    }
+
+    fn set_cell(&mut self, position: Self::Position, cell: T) {
+        self.set(position, cell)
+    }
+
+    fn for_each_position<F: FnMut(Self::Position)>(&self, f: F) {
+        if let Some(bounds) = self.bounds() {
+            bounds.grow(1).points().for_each(f)
+        }
+    }
+
+    fn reset(&self, next: &mut Self) {
+        next.clear()
+    }
+}
+
+// Which cells the rule gets to see for a position
+pub trait Neighbourhood<S: Space> {
+    type Neighbours<'a>: Iterator<Item = &'a S::Cell>
+    where
+        Self: 'a,
+        S: 'a;
+
+    fn neighbours<'a>(&'a self, space: &'a S, position: S::Position) -> Self::Neighbours<'a>;
+}
+
+// Horizontal, vertical and diagonal neighbours, the 3^N - 1 surrounding cells in N dimensions
+#[derive(Clone, Copy, Debug)]
+pub struct Moore;
+
+// Horizontal and vertical neighbours
+#[derive(Clone, Copy, Debug)]
+pub struct VonNeumann;
+
+// The six neighbours of a hexagon, the points of the grid are hexagons in axial coordinates
+#[derive(Clone, Copy, Debug)]
+pub struct Hexagonal;
+
+// The first cell satisfying the predicate in each of the eight directions
+#[derive(Clone, Copy, Debug)]
+pub struct LineOfSight<P>(pub P);
+
+impl<T: Clone + PartialEq> Neighbourhood<Grid<T>> for Moore {
+    type Neighbours<'a>
+        = GridNeighbours<'a, T>
+    where
+        T: 'a;
+
+    fn neighbours<'a>(
+        &'a self,
+        grid: &'a Grid<T>,
+        position: (usize, usize),
+    ) -> Self::Neighbours<'a> {
+        GridNeighbours::new(grid, position, &NEIGHBOURS_8)
+    }
+}
+
+impl<T: Clone + PartialEq> Neighbourhood<Grid<T>> for VonNeumann {
+    type Neighbours<'a>
+        = GridNeighbours<'a, T>
+    where
+        T: 'a;
+
+    fn neighbours<'a>(
+        &'a self,
+        grid: &'a Grid<T>,
+        position: (usize, usize),
+    ) -> Self::Neighbours<'a> {
+        GridNeighbours::new(grid, position, &NEIGHBOURS_4)
+    }
+}
+
+impl<T, P> Neighbourhood<Grid<T>> for LineOfSight<P>
+where
+    T: Clone + PartialEq,
+    P: Fn(&T) -> bool,
+{
+    type Neighbours<'a>
+        = Sight<'a, T, P>
+    where
+        T: 'a,
+        P: 'a;
+
+    fn neighbours<'a>(
+        &'a self,
+        grid: &'a Grid<T>,
+        position: (usize, usize),
+    ) -> Self::Neighbours<'a> {
+        Sight {
+            neighbours: GridNeighbours::new(grid, position, &NEIGHBOURS_8),
+            predicate: &self.0,
+        }
+    }
+}
+
+impl<T: Clone + PartialEq, const N: usize> Neighbourhood<SparseGrid<T, N>> for Moore {
+    type Neighbours<'a>
+        = SparseNeighbours<'a, T, N>
+    where
+        T: 'a;
+
+    fn neighbours<'a>(
+        &'a self,
+        grid: &'a SparseGrid<T, N>,
+        point: Point<N>,
+    ) -> Self::Neighbours<'a> {
+        SparseNeighbours {
+            grid,
+            point,
+            offsets: BoundingBox::new([-1; N], [1; N]).points(),
+        }
+    }
+}
+
+// Every neighbour of a hexagon is at most one step away in q and r, so the grown bounds of the
+// sparse grid cover them
+impl<T: Clone + PartialEq> Neighbourhood<SparseGrid<T, 2>> for Hexagonal {
+    type Neighbours<'a>
+        = HexNeighbours<'a, T>
+    where
+        T: 'a;
+
+    fn neighbours<'a>(
+        &'a self,
+        grid: &'a SparseGrid<T, 2>,
+        point: Point<2>,
+    ) -> Self::Neighbours<'a> {
+        HexNeighbours {
+            grid,
+            hex: Hex::from(point),
+            directions: HexDirection::ALL.iter(),
+        }
+    }
+}
+
+// Cells of a grid in the given directions, skipping the ones outside of the grid
+pub struct GridNeighbours<'a, T> {
+    grid: &'a Grid<T>,
+    position: (usize, usize),
+    directions: slice::Iter<'static, Direction>,
+}
+
+impl<'a, T> GridNeighbours<'a, T> {
+    fn new(grid: &'a Grid<T>, position: (usize, usize), directions: &'static [Direction]) -> Self {
+        Self {
+            grid,
+            position,
+            directions: directions.iter(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for GridNeighbours<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self {
+            grid,
+            position: (col, row),
+            directions,
+        } = self;
+        directions.find_map(|&direction| grid.get_offset(*col, *row, direction))
+    }
+}
+
+pub struct Sight<'a, T, P> {
+    neighbours: GridNeighbours<'a, T>,
+    predicate: &'a P,
+}
+
+impl<'a, T, P: Fn(&T) -> bool> Iterator for Sight<'a, T, P> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let GridNeighbours {
+            grid,
+            position: (col, row),
+            directions,
+        } = &mut self.neighbours;
+        let predicate = self.predicate;
+        directions.find_map(|&direction| {
+            grid.cast_ray(*col, *row, direction, predicate)
+                .map(|(_, t)| t)
+        })
+    }
+}
+
+pub struct SparseNeighbours<'a, T, const N: usize> {
+    grid: &'a SparseGrid<T, N>,
+    point: Point<N>,
+    offsets: Points<N>,
+}
+
+impl<'a, T: PartialEq, const N: usize> Iterator for SparseNeighbours<'a, T, N> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.offsets.find(|offset| offset.iter().any(|&d| d != 0))?;
+        Some(self.grid.get(&add(self.point, offset)))
+    }
+}
+
+pub struct HexNeighbours<'a, T> {
+    grid: &'a SparseGrid<T, 2>,
+    hex: Hex,
+    directions: slice::Iter<'static, HexDirection>,
+}
+
+impl<'a, T: PartialEq> Iterator for HexNeighbours<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let direction = *self.directions.next()?;
+        Some(self.grid.get(&self.hex.neighbour(direction).into()))
+    }
+}
+
+// The neighbours of a cell as seen by a rule
+pub struct Neighbours<'n, 'a, T>(&'n mut dyn Iterator<Item = &'a T>);
+
+impl<'a, T> Iterator for Neighbours<'_, 'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+// The first `start` steps lead into a cycle of `period` steps. A fixpoint is a cycle with a period
+// of 1.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+// Applies a rule to every cell at once. The rule gets the current cell and an iterator over its
+// neighbours and returns the next cell. The next step is written into a second buffer, which is
+// swapped with the current one afterwards.
+pub struct Automaton<S, N, R> {
+    current: S,
+    next: S,
+    neighbourhood: N,
+    rule: R,
+    generation: usize,
+}
+
+impl<S, N, R> Automaton<S, N, R>
+where
+    S: Space,
+    N: Neighbourhood<S>,
+    R: FnMut(&S::Cell, Neighbours<S::Cell>) -> S::Cell,
+{
+    pub fn new(space: S, neighbourhood: N, rule: R) -> Self {
+        Self {
+            next: space.clone(),
+            current: space,
+            neighbourhood,
+            rule,
+            generation: 0,
+        }
+    }
+
+    pub fn state(&self) -> &S {
+        &self.current
+    }
+
+    pub fn into_state(self) -> S {
+        self.current
+    }
+
+    // Number of steps applied so far
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    // Applies the rule once, returns whether any cell changed
+    pub fn step(&mut self) -> bool {
+        let changed = apply(
+            &self.current,
+            &mut self.next,
+            &self.neighbourhood,
+            &mut self.rule,
+        );
+        mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+        changed
+    }
+
+    pub fn run(&mut self, steps: usize) {
+        for _ in 0..steps {
+            self.step();
+        }
+    }
+
+    // Steps until no cell changes any more, returns the number of steps which changed a cell
+    pub fn run_to_fixpoint(&mut self) -> usize {
+        let mut steps = 0;
+        while self.step() {
+            steps += 1;
+        }
+        steps
+    }
+
+    // Looks for a repeating state within max_steps steps, counted from the current state, using
+    // Brent's algorithm. Afterwards the automaton is in the first state of the cycle, or back in
+    // the state it started from if there is none.
+    pub fn find_cycle(&mut self, max_steps: usize) -> Option<Cycle> {
+        let initial = self.current.clone();
+        let generation = self.generation;
+
+        // find the period by comparing with a snapshot taken at every power of two
+        let mut snapshot = self.current.clone();
+        let mut power = 1;
+        let mut period = 1;
+        self.step();
+        while self.current != snapshot {
+            if self.generation - generation >= max_steps {
+                self.current = initial;
+                self.generation = generation;
+                return None;
+            }
+            if power == period {
+                snapshot.clone_from(&self.current);
+                power *= 2;
+                period = 0;
+            }
+            self.step();
+            period += 1;
+        }
+
+        // the cycle starts where a state and the state a period ahead of it are equal
+        let mut behind = initial.clone();
+        let mut ahead = initial;
+        let mut scratch = snapshot;
+        for _ in 0..period {
+            self.advance(&mut ahead, &mut scratch);
+        }
+        let mut start = 0;
+        while behind != ahead {
+            self.advance(&mut behind, &mut scratch);
+            self.advance(&mut ahead, &mut scratch);
+            start += 1;
+        }
+
+        self.current = behind;
+        self.generation = generation + start;
+        Some(Cycle { start, period })
+    }
+
+    fn advance(&mut self, state: &mut S, scratch: &mut S) {
+        apply(state, scratch, &self.neighbourhood, &mut self.rule);
+        mem::swap(state, scratch);
+    }
+}
+
+// Writes the next step of current into next, returns whether any cell changed
+fn apply<S, N, R>(current: &S, next: &mut S, neighbourhood: &N, rule: &mut R) -> bool
+where
+    S: Space,
+    N: Neighbourhood<S>,
+    R: FnMut(&S::Cell, Neighbours<S::Cell>) -> S::Cell,
+{
+    current.reset(next);
+    let mut changed = false;
+    current.for_each_position(|position| {
+        let cell = current.cell(position);
+        let new = rule(
+            cell,
+            Neighbours(&mut neighbourhood.neighbours(current, position)),
+        );
+        changed |= new != *cell;
+        next.set_cell(position, new);
+    });
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Conway's game of life
+    fn life(cell: &char, neighbours: Neighbours<char>) -> char {
+        match (cell, neighbours.filter(|c| **c == '#').count()) {
+            ('#', 2..=3) | ('.', 3) => '#',
+            _ => '.',
+        }
+    }
+
+    fn grid(s: &str) -> Grid<char> {
+        s.parse().unwrap()
+    }
+
+    fn sparse(s: &str) -> SparseGrid<char, 2> {
+        let mut sparse = SparseGrid::new('.');
+        for ((col, row), c) in grid(s).items_iter() {
+            sparse.set([col as isize, row as isize], *c);
+        }
+        sparse
+    }
+
+    #[test]
+    fn test_run() {
+        let mut blinker = Automaton::new(grid(".....\n..#..\n..#..\n..#..\n....."), Moore, life);
+        assert!(blinker.step());
+        assert_eq!(&grid(".....\n.....\n.###.\n.....\n....."), blinker.state());
+        blinker.run(3);
+        assert_eq!(4, blinker.generation());
+        assert_eq!(&grid(".....\n..#..\n..#..\n..#..\n....."), blinker.state());
+    }
+
+    #[test]
+    fn test_run_to_fixpoint() {
+        // an L shape turns into a block, which never changes
+        let mut automaton = Automaton::new(grid("....\n.#..\n.##.\n...."), Moore, life);
+        assert_eq!(1, automaton.run_to_fixpoint());
+        assert_eq!(&grid("....\n.##.\n.##.\n...."), automaton.state());
+        assert_eq!(2, automaton.generation());
+
+        // a cell survives as long as a horizontal or vertical neighbour exists
+        let mut automaton =
+            Automaton::new(grid("#.#\n.##\n#.."), VonNeumann, |cell, mut neighbours| {
+                match neighbours.any(|c| *c == '#') {
+                    true => *cell,
+                    false => '.',
+                }
+            });
+        assert_eq!(1, automaton.run_to_fixpoint());
+        assert_eq!(&grid("..#\n.##\n..."), automaton.state());
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let mut blinker = Automaton::new(grid(".....\n..#..\n..#..\n..#..\n....."), Moore, life);
+        assert_eq!(
+            Some(Cycle {
+                start: 0,
+                period: 2
+            }),
+            blinker.find_cycle(10)
+        );
+
+        let mut block = Automaton::new(grid("....\n.#..\n.##.\n...."), Moore, life);
+        assert_eq!(
+            Some(Cycle {
+                start: 1,
+                period: 1
+            }),
+            block.find_cycle(10)
+        );
+        assert_eq!(1, block.generation());
+        assert_eq!(&grid("....\n.##.\n.##.\n...."), block.state());
+    }
+
+    #[test]
+    fn test_sparse() {
+        let mut blinker = Automaton::new(sparse(".#.\n.#.\n.#."), Moore, life);
+        blinker.step();
+        assert_eq!(&sparse("...\n###\n..."), blinker.state());
+        assert_eq!(
+            Some(Cycle {
+                start: 0,
+                period: 2
+            }),
+            blinker.find_cycle(10)
+        );
+
+        // a glider moves on forever
+        let mut glider = Automaton::new(sparse(".#.\n..#\n###"), Moore, life);
+        glider.run(4);
+        let bounds = glider.state().bounds().unwrap();
+        assert_eq!(([1, 1], [3, 3]), (bounds.min, bounds.max));
+        assert_eq!(5, glider.state().len());
+        let before = glider.state().clone();
+        assert_eq!(None, glider.find_cycle(100));
+        // the glider is left where it was
+        assert_eq!(4, glider.generation());
+        assert_eq!(&before, glider.state());
+    }
+
+    #[test]
+    fn test_hexagonal() {
+        // a hexagon grows by a ring of neighbours per step, to the centered hexagonal numbers
+        let mut grid = SparseGrid::new('.');
+        grid.set([0, 0], '#');
+        let mut growth = Automaton::new(grid, Hexagonal, |cell, mut neighbours| {
+            match *cell == '#' || neighbours.any(|c| *c == '#') {
+                true => '#',
+                false => '.',
+            }
+        });
+        let sizes: Vec<_> = (0..3)
+            .map(|_| {
+                growth.step();
+                growth.state().len()
+            })
+            .collect();
+        assert_eq!(vec![7, 19, 37], sizes);
+        assert_eq!(&'.', growth.state().get(&[3, 1]));
+        assert_eq!(&'#', growth.state().get(&[3, -3]));
+    }
+
+    #[test]
+    fn test_line_of_sight() {
+        let input = grid("#.#..\n.....\n#.L.#\n.....\n....#");
+        let sight = LineOfSight(|c: &char| *c != '.');
+        let seen: Vec<_> = sight.neighbours(&input, (2, 2)).collect();
+        assert_eq!(vec![&'#'; 5], seen);
+    }
+}
@@ -0,0 +1,183 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+// A parsing error at a position of the input. It is rendered with the offending line and carets
+// below the failing part:
+//
+// line 2, column 3: invalid digit found in string
+//   |
+// 2 | 1-x a: abcde
+//   |   ^
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError<E> {
+    // positions start at line 1 and column 1, columns count characters
+    pub line: usize,
+    pub column: usize,
+    // the line containing the error
+    pub snippet: String,
+    // number of characters the error spans, at least 1
+    pub len: usize,
+    pub error: E,
+}
+
+impl<E> ParseError<E> {
+    pub fn new(line: usize, column: usize, snippet: &str, len: usize, error: E) -> Self {
+        Self {
+            line,
+            column: column.max(1),
+            snippet: snippet.to_string(),
+            len: len.max(1),
+            error,
+        }
+    }
+
+    // Locates span, a slice of input, in the input. Spans across several lines are cut at the end
+    // of their first line. A span which is not part of the input is searched for, if it can not be
+    // found the error points at the start of the input.
+    pub fn at(input: &str, span: &str, error: E) -> Self {
+        let offset = offset_in(input, span)
+            .or_else(|| input.find(span))
+            .unwrap_or(0);
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line = before.matches('\n').count() + 1;
+        let column = before[line_start..].chars().count() + 1;
+        let snippet = input[line_start..].lines().next().unwrap_or("");
+        let len = span.lines().next().map_or(0, |l| l.chars().count());
+        Self::new(line, column, snippet, len, error)
+    }
+
+    // An error pointing right behind the last character of the input, e.g. for missing parts
+    pub fn end(input: &str, error: E) -> Self {
+        Self::at(input, &input[input.len()..], error)
+    }
+
+    // An error spanning a whole line, lines are counted from 1
+    pub fn line(line: usize, snippet: &str, error: E) -> Self {
+        Self::new(line, 1, snippet, snippet.chars().count(), error)
+    }
+
+    pub fn map<F, M: FnOnce(E) -> F>(self, f: M) -> ParseError<F> {
+        ParseError {
+            line: self.line,
+            column: self.column,
+            snippet: self.snippet,
+            len: self.len,
+            error: f(self.error),
+        }
+    }
+}
+
+// Byte offset of span in input, if span is a slice of input
+fn offset_in(input: &str, span: &str) -> Option<usize> {
+    let start = input.as_ptr() as usize;
+    let offset = (span.as_ptr() as usize).checked_sub(start)?;
+    if offset + span.len() <= input.len() {
+        Some(offset)
+    } else {
+        None
+    }
+}
+
+// Parses every line of the input with f. Errors span the whole failing line.
+pub fn parse_lines<'a, T, E, F>(input: &'a str, mut f: F) -> Result<Vec<T>, ParseError<E>>
+where
+    F: FnMut(&'a str) -> Result<T, E>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| ParseError::line(i + 1, line, e)))
+        .collect()
+}
+
+// implemented by hand, because the error is generic
+impl<E: Display> Display for ParseError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.error
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.len)
+        )
+    }
+}
+
+impl<E: Error + 'static> Error for ParseError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static INPUT: &str = "1-3 a: abcde\n1-x b: cdefg\n2-9 c: ccccccccc";
+
+    #[test]
+    fn test_at() {
+        let span = &INPUT[15..16];
+        assert_eq!("x", span);
+        let error = ParseError::at(INPUT, span, "not a number");
+        assert_eq!(
+            ParseError::new(2, 3, "1-x b: cdefg", 1, "not a number"),
+            error
+        );
+        assert_eq!(
+            "line 2, column 3: not a number\n  |\n2 | 1-x b: cdefg\n  |   ^",
+            error.to_string()
+        );
+
+        // spans are cut at the end of their line
+        let error = ParseError::at(INPUT, &INPUT[20..30], ());
+        assert_eq!((2, 8, 5), (error.line, error.column, error.len));
+
+        // spans which are not part of the input are searched for
+        let error = ParseError::at(INPUT, "ccc", ());
+        assert_eq!((3, 8, 3), (error.line, error.column, error.len));
+    }
+
+    #[test]
+    fn test_end_and_line() {
+        let error = ParseError::end("a\nbc", "missing d");
+        assert_eq!(ParseError::new(2, 3, "bc", 1, "missing d"), error);
+        assert_eq!(
+            "line 2, column 3: missing d\n  |\n2 | bc\n  |   ^",
+            error.to_string()
+        );
+
+        let error = ParseError::line(12, "abc", "bad line");
+        assert_eq!(
+            "line 12, column 1: bad line\n   |\n12 | abc\n   | ^^^",
+            error.to_string()
+        );
+
+        // columns start at 1, a column 0 points at the start of the line
+        let error = ParseError::new(1, 0, "abc", 0, "bad start");
+        assert_eq!((1, 1), (error.column, error.len));
+        assert_eq!(
+            "line 1, column 1: bad start\n  |\n1 | abc\n  | ^",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let numbers: Result<Vec<u32>, _> = parse_lines("1\n2\n3", str::parse);
+        assert_eq!(Ok(vec![1, 2, 3]), numbers);
+
+        let error = parse_lines("1\n2\nthree", str::parse::<u32>).unwrap_err();
+        assert_eq!((3, 1, 5), (error.line, error.column, error.len));
+        assert_eq!("three", error.snippet);
+    }
+}
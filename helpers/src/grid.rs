@@ -0,0 +1,727 @@
+use crate::error::ParseError;
+use itertools::Itertools;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use thiserror::Error;
+
+// (column, row) offset to a neighbouring cell, rows grow downwards
+pub type Direction = (isize, isize);
+
+// Neighbourhoods in reading order, left to right and top to bottom
+pub const NEIGHBOURS_4: [Direction; 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+pub const NEIGHBOURS_8: [Direction; 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+// A rectangular grid, stored row by row. Cells are addressed as (column, row), starting at the
+// upper left corner.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grid<T> {
+    columns: usize,
+    items: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn with_items(items: Vec<T>, columns: usize) -> Result<Self, GridError> {
+        let len = items.len();
+        if columns == 0 {
+            Err(GridError::NoColumns)
+        } else if len % columns == 0 {
+            Ok(Grid { columns, items })
+        } else {
+            Err(GridError::UnevenRowsError(len, columns))
+        }
+    }
+
+    fn get_idx(&self, col: usize, row: usize) -> usize {
+        row * self.columns + col
+    }
+
+    pub fn get(&self, col: usize, row: usize) -> Option<&T> {
+        if col < self.columns {
+            self.items.get(self.get_idx(col, row))
+        } else {
+            None
+        }
+    }
+
+    // Coordinates outside of the grid, including negative ones, are None
+    pub fn get_signed(&self, col: isize, row: isize) -> Option<&T> {
+        if col < 0 || row < 0 {
+            None
+        } else {
+            self.get(col as usize, row as usize)
+        }
+    }
+
+    // The cell at the given offset from (col, row), None if that is outside of the grid
+    pub fn get_offset(&self, col: usize, row: usize, direction: Direction) -> Option<&T> {
+        self.offset(col, row, direction)
+            .map(|(col, row)| self.get_unchecked(col, row))
+    }
+
+    // Coordinates of the cell at the given offset from (col, row), if it is inside of the grid
+    pub fn offset(
+        &self,
+        col: usize,
+        row: usize,
+        (d_col, d_row): Direction,
+    ) -> Option<(usize, usize)> {
+        let col = col.checked_add_signed(d_col)?;
+        let row = row.checked_add_signed(d_row)?;
+        if col < self.num_columns() && row < self.num_rows() {
+            Some((col, row))
+        } else {
+            None
+        }
+    }
+
+    pub fn num_columns(&self) -> usize {
+        self.columns
+    }
+
+    // A grid without columns has no rows either
+    pub fn num_rows(&self) -> usize {
+        self.items.len().checked_div(self.columns).unwrap_or(0)
+    }
+
+    pub fn set(&mut self, col: usize, row: usize, v: T) {
+        let idx = self.get_idx(col, row);
+        self.items[idx] = v
+    }
+
+    fn get_unchecked(&self, col: usize, row: usize) -> &T {
+        if col < self.columns {
+            &self.items[self.get_idx(col, row)]
+        } else {
+            panic!("Index out of bounds!")
+        }
+    }
+
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    pub fn items_iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        (0..self.num_rows())
+            .cartesian_product(0..self.columns)
+            .map(move |(row, col)| ((col, row), self.get_unchecked(col, row)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.items.iter()
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        if row >= self.num_rows() {
+            return None;
+        }
+        let start = self.get_idx(0, row);
+        self.items.get(start..start + self.columns)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // a grid without columns has no items, any chunk size will do
+        self.items.chunks(self.columns.max(1))
+    }
+
+    // Cells of a column from top to bottom, empty if the column is outside of the grid
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        let cells = match self.items.get(col..) {
+            Some(cells) if col < self.columns => cells,
+            _ => &[],
+        };
+        cells.iter().step_by(self.columns.max(1))
+    }
+
+    // Neighbouring cells in the given directions, skipping the ones outside of the grid
+    pub fn neighbours<'a>(
+        &'a self,
+        col: usize,
+        row: usize,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = ((usize, usize), &'a T)> + 'a {
+        directions.iter().filter_map(move |&direction| {
+            self.offset(col, row, direction)
+                .map(|(col, row)| ((col, row), self.get_unchecked(col, row)))
+        })
+    }
+
+    // Horizontal and vertical neighbours
+    pub fn neighbours4(
+        &self,
+        col: usize,
+        row: usize,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.neighbours(col, row, &NEIGHBOURS_4)
+    }
+
+    // Horizontal, vertical and diagonal neighbours
+    pub fn neighbours8(
+        &self,
+        col: usize,
+        row: usize,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.neighbours(col, row, &NEIGHBOURS_8)
+    }
+
+    // All cells from (col, row) in the given direction up to the border of the grid, excluding
+    // the starting cell
+    pub fn ray(
+        &self,
+        col: usize,
+        row: usize,
+        direction: Direction,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        let mut position = Some((col, row));
+        std::iter::from_fn(move || {
+            let (col, row) = position?;
+            position = self.offset(col, row, direction);
+            position
+        })
+        .map(move |(col, row)| ((col, row), self.get_unchecked(col, row)))
+    }
+
+    // The first cell from (col, row) in the given direction satisfying the predicate
+    pub fn cast_ray<P>(
+        &self,
+        col: usize,
+        row: usize,
+        direction: Direction,
+        mut predicate: P,
+    ) -> Option<((usize, usize), &T)>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.ray(col, row, direction).find(|(_, t)| predicate(t))
+    }
+
+    // A grid of the same size with f applied to every cell
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            columns: self.columns,
+            items: self.items.iter().map(f).collect(),
+        }
+    }
+
+    // Like map, but f also gets the coordinates of the cell, so it can take the neighbourhood into
+    // account
+    pub fn transform<U, F>(&self, mut f: F) -> Grid<U>
+    where
+        F: FnMut((usize, usize), &T) -> U,
+    {
+        Grid {
+            columns: self.columns,
+            items: self
+                .items
+                .iter()
+                .enumerate()
+                .map(|(i, t)| f((i % self.columns, i / self.columns), t))
+                .collect(),
+        }
+    }
+}
+
+// Geometric transforms, each one returns a new grid. The cells of an empty grid are never looked up,
+// so the last column or row of it does not matter.
+impl<T: Clone> Grid<T> {
+    // A grid with every cell set to value
+    pub fn filled(columns: usize, rows: usize, value: T) -> Self {
+        Grid {
+            columns,
+            items: vec![value; columns * rows],
+        }
+    }
+
+    // A grid with cell (col, row) set to f(col, row)
+    pub fn from_fn<F>(columns: usize, rows: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        Grid {
+            columns,
+            items: (0..rows)
+                .cartesian_product(0..columns)
+                .map(|(row, col)| f(col, row))
+                .collect(),
+        }
+    }
+
+    // Mirrors the grid along the diagonal from the upper left to the lower right corner
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.num_rows(), self.num_columns(), |col, row| {
+            self.get_unchecked(row, col).clone()
+        })
+    }
+
+    // Mirrors left and right
+    pub fn flip_horizontal(&self) -> Self {
+        let last_col = self.num_columns().saturating_sub(1);
+        self.transform_positions(|col, row| (last_col - col, row))
+    }
+
+    // Mirrors top and bottom
+    pub fn flip_vertical(&self) -> Self {
+        let last_row = self.num_rows().saturating_sub(1);
+        self.transform_positions(|col, row| (col, last_row - row))
+    }
+
+    // Rotates clockwise by 90 degrees
+    pub fn rotate_90(&self) -> Self {
+        let last_row = self.num_rows().saturating_sub(1);
+        Self::from_fn(self.num_rows(), self.num_columns(), |col, row| {
+            self.get_unchecked(row, last_row - col).clone()
+        })
+    }
+
+    pub fn rotate_180(&self) -> Self {
+        let (last_col, last_row) = (
+            self.num_columns().saturating_sub(1),
+            self.num_rows().saturating_sub(1),
+        );
+        self.transform_positions(|col, row| (last_col - col, last_row - row))
+    }
+
+    // Rotates clockwise by 270 degrees, which is counterclockwise by 90 degrees
+    pub fn rotate_270(&self) -> Self {
+        let last_col = self.num_columns().saturating_sub(1);
+        Self::from_fn(self.num_rows(), self.num_columns(), |col, row| {
+            self.get_unchecked(last_col - row, col).clone()
+        })
+    }
+
+    // All 8 orientations reachable by rotating and flipping, starting with the grid itself.
+    // The first four are the clockwise rotations, the last four the rotations of the flipped grid.
+    pub fn orientations(&self) -> impl Iterator<Item = Self> + '_ {
+        let flipped = self.flip_horizontal();
+        (0..8).map(move |i| {
+            let grid = if i < 4 { self } else { &flipped };
+            match i % 4 {
+                0 => grid.clone(),
+                1 => grid.rotate_90(),
+                2 => grid.rotate_180(),
+                _ => grid.rotate_270(),
+            }
+        })
+    }
+
+    // Copy of the columns x rows cells starting at (col, row), None if they are not all inside
+    // of the grid
+    pub fn sub_grid(&self, col: usize, row: usize, columns: usize, rows: usize) -> Option<Self> {
+        if columns == 0
+            || rows == 0
+            || col + columns > self.num_columns()
+            || row + rows > self.num_rows()
+        {
+            return None;
+        }
+        Some(Self::from_fn(columns, rows, |c, r| {
+            self.get_unchecked(col + c, row + r).clone()
+        }))
+    }
+
+    // Cells along a border, top and bottom from left to right, left and right from top to bottom
+    pub fn edge(&self, edge: Edge) -> Vec<T> {
+        match edge {
+            Edge::Top => self.row(0).map(<[T]>::to_vec).unwrap_or_default(),
+            Edge::Bottom => self
+                .row(self.num_rows().saturating_sub(1))
+                .map(<[T]>::to_vec)
+                .unwrap_or_default(),
+            Edge::Left => self.column(0).cloned().collect(),
+            Edge::Right => self
+                .column(self.num_columns().saturating_sub(1))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    // Overwrites the cells starting at (col, row) with the cells of other
+    pub fn paste(&mut self, other: &Grid<T>, col: usize, row: usize) -> Result<(), GridError> {
+        if col + other.num_columns() > self.num_columns()
+            || row + other.num_rows() > self.num_rows()
+        {
+            return Err(GridError::OutOfBounds(
+                other.num_columns(),
+                other.num_rows(),
+                col,
+                row,
+            ));
+        }
+        for (r, cells) in other.rows().enumerate() {
+            let start = self.get_idx(col, row + r);
+            self.items[start..start + cells.len()].clone_from_slice(cells);
+        }
+        Ok(())
+    }
+
+    // the cell at (col, row) of the new grid is taken from position(col, row) of this grid
+    fn transform_positions<F>(&self, position: F) -> Self
+    where
+        F: Fn(usize, usize) -> (usize, usize),
+    {
+        Self::from_fn(self.num_columns(), self.num_rows(), |col, row| {
+            let (col, row) = position(col, row);
+            self.get_unchecked(col, row).clone()
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Edge {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, item) in self.items.iter().enumerate() {
+            if i % self.columns == 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", item)?;
+        }
+        Ok(())
+    }
+}
+
+// Parses a map with one character per cell, e.g.
+// #.#
+// ..#
+// All lines must have the same length.
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = ParseGridError<T::Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut columns = None;
+        let mut items = Vec::with_capacity(s.len());
+        for (line_idx, line) in s.lines().enumerate() {
+            let line_nr = line_idx + 1;
+            let mut width = 0;
+            for (column, c) in line.chars().enumerate() {
+                let cell = T::try_from(c).map_err(|error| {
+                    ParseError::new(line_nr, column + 1, line, 1, InvalidGrid::Cell(error))
+                })?;
+                items.push(cell);
+                width += 1;
+            }
+
+            let expected = *columns.get_or_insert(width);
+            if width != expected {
+                let error = InvalidGrid::UnevenRow {
+                    expected,
+                    found: width,
+                };
+                return Err(ParseError::line(line_nr, line, error));
+            }
+        }
+
+        match columns {
+            Some(columns) if columns > 0 => Ok(Grid { columns, items }),
+            _ => Err(ParseError::end(s, InvalidGrid::Empty)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Error)]
+pub enum GridError {
+    #[error("{0} items can not be divided among {1} columns")]
+    UnevenRowsError(usize, usize),
+    #[error("a grid needs at least one column")]
+    NoColumns,
+    #[error("a grid with {0} columns and {1} rows does not fit at ({2}, {3})")]
+    OutOfBounds(usize, usize, usize, usize),
+}
+
+pub type ParseGridError<E> = ParseError<InvalidGrid<E>>;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InvalidGrid<E> {
+    Cell(E),
+    UnevenRow { expected: usize, found: usize },
+    Empty,
+}
+
+// implemented by hand, because the cell error is generic
+impl<E: Display> Display for InvalidGrid<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidGrid::Cell(error) => write!(f, "{}", error),
+            InvalidGrid::UnevenRow { expected, found } => write!(
+                f,
+                "the line has {} columns, expected {} like the first line",
+                found, expected
+            ),
+            InvalidGrid::Empty => write!(f, "the grid has no cells"),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for InvalidGrid<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InvalidGrid::Cell(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 1 2
+    // 3 4 5
+    fn grid() -> Grid<u8> {
+        Grid::with_items((0..6).collect(), 3).unwrap()
+    }
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    enum Cell {
+        Open,
+        Wall,
+    }
+
+    impl TryFrom<char> for Cell {
+        type Error = char;
+
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            match c {
+                '.' => Ok(Cell::Open),
+                '#' => Ok(Cell::Wall),
+                c => Err(c),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid: Grid<Cell> = ".#.\n##.\n".parse().unwrap();
+        assert_eq!(3, grid.num_columns());
+        assert_eq!(2, grid.num_rows());
+        assert_eq!(Some(&Cell::Wall), grid.get(1, 0));
+        assert_eq!(Some(&Cell::Open), grid.get(2, 1));
+
+        let grid: Grid<char> = "ab\ncd".parse().unwrap();
+        assert_eq!(&['a', 'b', 'c', 'd'], grid.items());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(ParseError::new(2, 3, "##x", 1, InvalidGrid::Cell('x'))),
+            ".#.\n##x".parse::<Grid<Cell>>()
+        );
+        assert_eq!(
+            Err(ParseError::line(
+                3,
+                ".#",
+                InvalidGrid::UnevenRow {
+                    expected: 3,
+                    found: 2
+                }
+            )),
+            ".#.\n##.\n.#".parse::<Grid<Cell>>()
+        );
+        assert_eq!(
+            Err(ParseError::end("", InvalidGrid::Empty)),
+            "".parse::<Grid<Cell>>()
+        );
+        assert_eq!(
+            InvalidGrid::Empty,
+            "\n\n".parse::<Grid<Cell>>().unwrap_err().error
+        );
+
+        let error = ".#.\n#?.".parse::<Grid<Cell>>().unwrap_err();
+        assert_eq!(
+            "line 2, column 2: ?\n  |\n2 | #?.\n  |  ^",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_get_signed_and_offset() {
+        let grid = grid();
+        assert_eq!(Some(&4), grid.get_signed(1, 1));
+        assert_eq!(None, grid.get_signed(-1, 1));
+        assert_eq!(None, grid.get_signed(1, 2));
+
+        assert_eq!(Some(&2), grid.get_offset(1, 1, (1, -1)));
+        assert_eq!(None, grid.get_offset(0, 0, (-1, 0)));
+        assert_eq!(None, grid.get_offset(2, 0, (1, 0)));
+        assert_eq!(Some((0, 1)), grid.offset(2, 0, (-2, 1)));
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(Some(&[3, 4, 5][..]), grid.row(1));
+        assert_eq!(None, grid.row(2));
+        assert_eq!(
+            vec![&[0, 1, 2][..], &[3, 4, 5][..]],
+            grid.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(vec![&2, &5], grid.column(2).collect::<Vec<_>>());
+        assert_eq!(0, grid.column(3).count());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+        let n4: Vec<_> = grid.neighbours4(0, 0).map(|(_, n)| *n).collect();
+        assert_eq!(vec![1, 3], n4);
+        let n4: Vec<_> = grid.neighbours4(1, 1).map(|(_, n)| *n).collect();
+        assert_eq!(vec![1, 3, 5], n4);
+        let n8: Vec<_> = grid.neighbours8(1, 0).collect();
+        assert_eq!(
+            vec![
+                ((0, 0), &0),
+                ((2, 0), &2),
+                ((0, 1), &3),
+                ((1, 1), &4),
+                ((2, 1), &5)
+            ],
+            n8
+        );
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = Grid::with_items((0..16).collect::<Vec<u8>>(), 4).unwrap();
+        let diagonal: Vec<_> = grid.ray(0, 0, (1, 1)).map(|(_, n)| *n).collect();
+        assert_eq!(vec![5, 10, 15], diagonal);
+        assert_eq!(0, grid.ray(3, 3, (1, 1)).count());
+
+        assert_eq!(
+            Some(((3, 2), &11)),
+            grid.cast_ray(3, 0, (0, 1), |n| n % 11 == 0)
+        );
+        assert_eq!(None, grid.cast_ray(3, 0, (0, 1), |n| *n == 3));
+    }
+
+    #[test]
+    fn test_map_and_transform() {
+        let grid = grid();
+        let doubled = grid.map(|n| n * 2);
+        assert_eq!(&[0, 2, 4, 6, 8, 10], doubled.items());
+        assert_eq!(3, doubled.num_columns());
+
+        let neighbours = grid.transform(|(col, row), _| grid.neighbours4(col, row).count());
+        assert_eq!(&[2, 3, 2, 2, 3, 2], neighbours.items());
+    }
+
+    // 0 1 2
+    // 3 4 5
+    #[test]
+    fn test_flip_and_transpose() {
+        let grid = grid();
+        assert_eq!(&[2, 1, 0, 5, 4, 3], grid.flip_horizontal().items());
+        assert_eq!(&[3, 4, 5, 0, 1, 2], grid.flip_vertical().items());
+
+        let transposed = grid.transpose();
+        assert_eq!(2, transposed.num_columns());
+        assert_eq!(&[0, 3, 1, 4, 2, 5], transposed.items());
+        assert_eq!(grid, transposed.transpose());
+    }
+
+    #[test]
+    fn test_rotate() {
+        let grid = grid();
+        // 3 0
+        // 4 1
+        // 5 2
+        let rotated = grid.rotate_90();
+        assert_eq!(2, rotated.num_columns());
+        assert_eq!(&[3, 0, 4, 1, 5, 2], rotated.items());
+        assert_eq!(&[5, 4, 3, 2, 1, 0], grid.rotate_180().items());
+        assert_eq!(&[2, 5, 1, 4, 0, 3], grid.rotate_270().items());
+
+        assert_eq!(grid.rotate_180(), rotated.rotate_90());
+        assert_eq!(grid, rotated.rotate_270());
+        assert_eq!(grid, grid.rotate_90().rotate_90().rotate_90().rotate_90());
+    }
+
+    #[test]
+    fn test_orientations() {
+        let grid = grid();
+        let orientations: Vec<_> = grid.orientations().collect();
+        assert_eq!(8, orientations.len());
+        assert_eq!(grid, orientations[0]);
+        // no two orientations of an asymmetric grid are the same
+        for (i, a) in orientations.iter().enumerate() {
+            assert!(orientations[i + 1..].iter().all(|b| a != b));
+        }
+        assert!(orientations.contains(&grid.transpose()));
+        assert!(orientations.contains(&grid.flip_vertical()));
+
+        // all orientations of a symmetric grid are the same
+        let symmetric = Grid::filled(2, 2, 1);
+        assert!(symmetric.orientations().all(|o| o == symmetric));
+    }
+
+    #[test]
+    fn test_sub_grid_and_edges() {
+        let grid = Grid::with_items((0..16).collect::<Vec<u8>>(), 4).unwrap();
+        let sub = grid.sub_grid(1, 1, 2, 3).unwrap();
+        assert_eq!(&[5, 6, 9, 10, 13, 14], sub.items());
+        assert_eq!(None, grid.sub_grid(3, 0, 2, 1));
+        assert_eq!(None, grid.sub_grid(0, 0, 0, 1));
+
+        assert_eq!(vec![0, 1, 2, 3], grid.edge(Edge::Top));
+        assert_eq!(vec![3, 7, 11, 15], grid.edge(Edge::Right));
+        assert_eq!(vec![12, 13, 14, 15], grid.edge(Edge::Bottom));
+        assert_eq!(vec![0, 4, 8, 12], grid.edge(Edge::Left));
+    }
+
+    #[test]
+    fn test_paste() {
+        let mut grid = Grid::filled(4, 3, 0);
+        grid.paste(&Grid::filled(2, 2, 1), 1, 1).unwrap();
+        assert_eq!(&[0, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0], grid.items());
+
+        assert!(grid.paste(&Grid::filled(2, 2, 2), 3, 0).is_err());
+        assert!(grid.paste(&Grid::filled(2, 2, 2), 0, 2).is_err());
+        assert_eq!(Some(&[0, 1, 1, 0][..]), grid.row(2));
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(matches!(
+            Grid::<u8>::with_items(vec![], 0),
+            Err(GridError::NoColumns)
+        ));
+
+        // three columns without any rows, the transforms turn it into a grid without columns
+        let empty = Grid::<u8>::with_items(vec![], 3).unwrap();
+        assert_eq!((3, 0), (empty.num_columns(), empty.num_rows()));
+        for grid in empty
+            .orientations()
+            .chain(vec![empty.flip_vertical(), empty.transpose()])
+        {
+            assert!(grid.items().is_empty());
+            assert_eq!(0, grid.num_rows());
+            assert_eq!(0, grid.rows().count());
+            assert_eq!(0, grid.column(0).count());
+            assert_eq!(0, grid.items_iter().count());
+            assert_eq!(None, grid.row(0));
+            assert_eq!("", grid.to_string());
+        }
+        assert_eq!(0, empty.rotate_90().num_columns());
+        assert!(empty.edge(Edge::Bottom).is_empty());
+        assert!(empty.edge(Edge::Right).is_empty());
+    }
+}
@@ -0,0 +1,125 @@
+use crate::sparse::Point;
+use std::ops::Add;
+
+// A hexagon of a hexagonal grid in axial coordinates. The hexagons have pointy tops, they form
+// rows from west to east. q grows to the east and r to the south east, the third cube coordinate
+// s = -q - r is implied. As a point of a SparseGrid a hexagon is [q, r].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Hex {
+    pub q: isize,
+    pub r: isize,
+}
+
+impl Hex {
+    pub fn new(q: isize, r: isize) -> Self {
+        Self { q, r }
+    }
+
+    pub fn s(&self) -> isize {
+        -self.q - self.r
+    }
+
+    pub fn neighbour(self, direction: HexDirection) -> Self {
+        self + direction.offset()
+    }
+
+    // The six adjacent hexagons, in the order of HexDirection::ALL
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        HexDirection::ALL.iter().map(move |&d| self.neighbour(d))
+    }
+
+    // Number of steps to the other hexagon
+    pub fn distance(&self, other: &Self) -> usize {
+        let (q, r) = (self.q - other.q, self.r - other.r);
+        let s = -q - r;
+        (q.unsigned_abs() + r.unsigned_abs() + s.unsigned_abs()) / 2
+    }
+}
+
+impl Add for Hex {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Hex::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl From<Point<2>> for Hex {
+    fn from([q, r]: Point<2>) -> Self {
+        Hex::new(q, r)
+    }
+}
+
+impl From<Hex> for Point<2> {
+    fn from(hex: Hex) -> Self {
+        [hex.q, hex.r]
+    }
+}
+
+// The directions to the six neighbours of a hexagon with a pointy top
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum HexDirection {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl HexDirection {
+    // clockwise, starting in the east
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::East,
+        HexDirection::SouthEast,
+        HexDirection::SouthWest,
+        HexDirection::West,
+        HexDirection::NorthWest,
+        HexDirection::NorthEast,
+    ];
+
+    pub fn offset(self) -> Hex {
+        match self {
+            HexDirection::East => Hex::new(1, 0),
+            HexDirection::SouthEast => Hex::new(0, 1),
+            HexDirection::SouthWest => Hex::new(-1, 1),
+            HexDirection::West => Hex::new(-1, 0),
+            HexDirection::NorthWest => Hex::new(0, -1),
+            HexDirection::NorthEast => Hex::new(1, -1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use HexDirection::*;
+
+    fn walk(directions: &[HexDirection]) -> Hex {
+        directions
+            .iter()
+            .fold(Hex::default(), |hex, &d| hex.neighbour(d))
+    }
+
+    #[test]
+    fn test_walk() {
+        // going around a hexagon leads back to the start
+        assert_eq!(Hex::default(), walk(&HexDirection::ALL));
+        assert_eq!(
+            Hex::default(),
+            walk(&[NorthWest, West, SouthWest, East, East])
+        );
+        assert_eq!(walk(&[SouthEast]), walk(&[East, SouthWest]));
+        let hex = walk(&[East, East, NorthEast]);
+        assert_eq!((3, -1, -2), (hex.q, hex.r, hex.s()));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let hex = Hex::new(2, -5);
+        assert!(hex.neighbours().all(|n| n.distance(&hex) == 1));
+        assert_eq!(Some(hex), hex.neighbour(West).neighbours().next());
+        assert_eq!(3, hex.distance(&(hex + walk(&[East, East, NorthEast]))));
+        assert_eq!(hex, Hex::from(Point::from(hex)));
+    }
+}
@@ -0,0 +1,155 @@
+use std::ffi::OsString;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+// Fetching needs an HTTP client with TLS, only the runner enables it
+#[cfg(feature = "fetch")]
+mod fetch;
+#[cfg(feature = "fetch")]
+pub use fetch::{FetchError, InputSource, BASE_URL_VAR, DEFAULT_BASE_URL, SESSION_VAR};
+
+// Directory with the puzzle inputs in `days` and the expected answers, defaults to the `assets`
+// directory of the workspace, independent of the working directory
+pub const ASSETS_DIR_VAR: &str = "AOC_ASSETS_DIR";
+
+pub fn assets_dir() -> PathBuf {
+    assets_dir_from(std::env::var_os(ASSETS_DIR_VAR))
+}
+
+fn assets_dir_from(var: Option<OsString>) -> PathBuf {
+    match var {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => Path::new(env!("CARGO_MANIFEST_DIR")).join("../assets"),
+    }
+}
+
+// Directory of the puzzle inputs, which is also the cache of `InputSource`
+pub fn days_dir() -> PathBuf {
+    assets_dir().join("days")
+}
+
+// `{assets}/days/day{day}.txt`
+pub fn day_path(day: u8) -> PathBuf {
+    days_dir().join(format!("day{}.txt", day))
+}
+
+// Reads the puzzle input of a day from the assets directory
+pub fn read_day(day: u8) -> Result<String, InputError> {
+    read(day_path(day)).map_err(|e| missing_day(day, e))
+}
+
+// Opens the puzzle input of a day from the assets directory, to be read lazily
+pub fn open_day(day: u8) -> Result<Box<dyn BufRead>, InputError> {
+    open(day_path(day)).map_err(|e| missing_day(day, e))
+}
+
+fn missing_day(day: u8, e: InputError) -> InputError {
+    match e {
+        InputError::NotFound(path) => InputError::MissingDay(day, path),
+        e => e,
+    }
+}
+
+// Reads a puzzle input from a file, `-` reads from stdin instead
+pub fn read<P: AsRef<Path>>(path: P) -> Result<String, InputError> {
+    let path = path.as_ref();
+    let mut content = String::new();
+    open(path)?
+        .read_to_string(&mut content)
+        .map_err(|e| InputError::Read(path.to_path_buf(), e))?;
+    Ok(content)
+}
+
+// Opens a puzzle input to be read lazily, e.g. line by line with `stream::parsed_lines`, `-` reads
+// from stdin instead
+pub fn open<P: AsRef<Path>>(path: P) -> Result<Box<dyn BufRead>, InputError> {
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+    match fs::File::open(path) {
+        Ok(file) => Ok(Box::new(BufReader::new(file))),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            Err(InputError::NotFound(path.to_path_buf()))
+        }
+        Err(e) => Err(InputError::Read(path.to_path_buf(), e)),
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum InputError {
+    #[error("input file {} does not exist", .0.display())]
+    NotFound(PathBuf),
+    #[error(
+        "there is no input for day {0}, {} does not exist, pass another file or set {}",
+        .1.display(),
+        ASSETS_DIR_VAR
+    )]
+    MissingDay(u8, PathBuf),
+    #[error("could not read input file {}, {}", .0.display(), .1)]
+    Read(PathBuf, #[source] io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("helpers-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_assets_dir() {
+        let dir = assets_dir_from(None);
+        assert!(dir.join("answers.toml").is_file());
+        assert_eq!(dir, assets_dir_from(Some(OsString::new())));
+        assert_eq!(
+            PathBuf::from("/elsewhere"),
+            assets_dir_from(Some("/elsewhere".into()))
+        );
+    }
+
+    #[test]
+    fn test_read() {
+        let dir = temp_dir("read");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day1.txt"), "1721\n").unwrap();
+        assert_eq!("1721\n", read(dir.join("day1.txt")).unwrap());
+
+        // a missing input names the file
+        let error = read(dir.join("day2.txt")).unwrap_err();
+        assert!(matches!(&error, InputError::NotFound(path) if path == &dir.join("day2.txt")));
+        assert!(error.to_string().contains("day2.txt does not exist"));
+        // the assets directory only matters if the input was looked up there
+        assert!(!error.to_string().contains(ASSETS_DIR_VAR));
+        let error = read_day(26).unwrap_err();
+        assert!(matches!(&error, InputError::MissingDay(26, path) if path == &day_path(26)));
+        assert!(error.to_string().contains(ASSETS_DIR_VAR));
+        assert!(matches!(read(&dir), Err(InputError::Read(..))));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_open() {
+        let dir = temp_dir("open");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day1.txt"), "1721\n979\n").unwrap();
+        let lines: Vec<_> = open(dir.join("day1.txt"))
+            .unwrap()
+            .lines()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(vec!["1721", "979"], lines);
+
+        assert!(matches!(
+            open(dir.join("day2.txt")),
+            Err(InputError::NotFound(_))
+        ));
+        assert!(matches!(open_day(26), Err(InputError::MissingDay(26, _))));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,191 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+// Puzzle inputs are personal, the puzzle server identifies the user by the session cookie of a
+// logged in browser
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com/2020";
+
+// Downloads puzzle inputs from `{base_url}/day/{day}/input` and caches them as
+// `{cache_dir}/day{day}.txt`. Cached inputs never change, so they are never fetched again.
+#[derive(Clone, Debug)]
+pub struct InputSource {
+    base_url: String,
+    session: String,
+    cache_dir: PathBuf,
+}
+
+impl InputSource {
+    pub fn new<P: Into<PathBuf>>(base_url: &str, session: &str, cache_dir: P) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            cache_dir: cache_dir.into(),
+        }
+    }
+
+    // Reads the session token from `AOC_SESSION` and an optional base url from `AOC_BASE_URL`
+    pub fn from_env<P: Into<PathBuf>>(cache_dir: P) -> Result<Self, FetchError> {
+        let session = std::env::var(SESSION_VAR).map_err(|_| FetchError::MissingSession)?;
+        let base_url = std::env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Ok(Self::new(&base_url, &session, cache_dir))
+    }
+
+    pub fn cache_path(&self, day: u8) -> PathBuf {
+        self.cache_dir.join(format!("day{}.txt", day))
+    }
+
+    // Returns the cached input of the day, fetching and caching it first if necessary
+    pub fn get(&self, day: u8) -> Result<String, FetchError> {
+        let path = self.cache_path(day);
+        if path.is_file() {
+            return Ok(fs::read_to_string(path)?);
+        }
+        let input = self.fetch(day)?;
+        write_atomically(&path, &input)?;
+        Ok(input)
+    }
+
+    fn fetch(&self, day: u8) -> Result<String, FetchError> {
+        let url = format!("{}/day/{}/input", self.base_url, day);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(status, _) => FetchError::Status(url.clone(), status),
+                e => FetchError::Request(Box::new(e)),
+            })?;
+        Ok(response.into_string()?)
+    }
+}
+
+// An interrupted download must not leave a truncated input in the cache
+fn write_atomically(path: &Path, content: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let partial = path.with_extension("part");
+    fs::write(&partial, content)?;
+    fs::rename(partial, path)
+}
+
+#[derive(Debug, Error)]
+pub enum FetchError {
+    #[error("no session token, set {}", SESSION_VAR)]
+    MissingSession,
+    #[error("fetching {0} failed with status {1}")]
+    Status(String, u16),
+    #[error("fetching the input failed")]
+    Request(#[from] Box<ureq::Error>),
+    #[error("could not read or write cached input")]
+    Io(#[from] io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    // Stand-in for the puzzle server, answering every request with the given status and body.
+    // Returns the base url and the number of requests served so far.
+    fn serve(status: &'static str, body: &'static str) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/2020", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let served = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut head = Vec::new();
+                for line in BufReader::new(&stream).lines() {
+                    let line = line.unwrap();
+                    if line.is_empty() {
+                        break;
+                    }
+                    head.push(line);
+                }
+                let authorized = head.iter().any(|l| l == "Cookie: session=secret");
+                let found = head[0].starts_with("GET /2020/day/") && head[0].contains("/input ");
+                let status = if authorized && found {
+                    status
+                } else {
+                    "400 Bad Request"
+                };
+                served.fetch_add(1, Ordering::SeqCst);
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        (url, requests)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("helpers-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_and_cache() {
+        let (url, requests) = serve("200 OK", "1721\n979\n");
+        let dir = temp_dir("fetch");
+        let source = InputSource::new(&url, "secret", &dir);
+
+        assert_eq!("1721\n979\n", source.get(1).unwrap());
+        assert_eq!(1, requests.load(Ordering::SeqCst));
+        assert_eq!(
+            "1721\n979\n",
+            fs::read_to_string(dir.join("day1.txt")).unwrap()
+        );
+
+        // the cached input is used, the server is not asked again
+        assert_eq!("1721\n979\n", source.get(1).unwrap());
+        assert_eq!(1, requests.load(Ordering::SeqCst));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_never_refetch_existing_input() {
+        let (url, requests) = serve("200 OK", "new");
+        let dir = temp_dir("existing");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day7.txt"), "committed").unwrap();
+        let source = InputSource::new(&url, "secret", &dir);
+
+        assert_eq!("committed", source.get(7).unwrap());
+        assert_eq!(0, requests.load(Ordering::SeqCst));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_failed_fetch_is_not_cached() {
+        let (url, requests) = serve("404 Not Found", "Please don't repeatedly request this");
+        let dir = temp_dir("failed");
+        let source = InputSource::new(&url, "secret", &dir);
+
+        assert!(matches!(source.get(26), Err(FetchError::Status(_, 404))));
+        assert!(!source.cache_path(26).exists());
+        assert_eq!(1, requests.load(Ordering::SeqCst));
+
+        let unauthorized = InputSource::new(&url, "wrong", &dir);
+        assert!(matches!(
+            unauthorized.get(1),
+            Err(FetchError::Status(_, 400))
+        ));
+        let _ = fs::remove_dir_all(dir);
+    }
+}
@@ -1,4 +1,3 @@
-use itertools::Itertools;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::fs::File;
@@ -7,19 +6,34 @@ use std::path::Path;
 use std::str::FromStr;
 use thiserror::Error;
 
+pub mod automaton;
+pub mod error;
+pub mod grid;
+pub mod hex;
+pub mod input;
+pub mod matching;
+pub mod parser;
+pub mod sparse;
+pub mod stream;
+
+pub use error::{parse_lines, ParseError};
+pub use grid::{Grid, GridError, InvalidGrid, ParseGridError};
+pub use sparse::SparseGrid;
+pub use stream::ReadError;
+
 // PARSING
 pub fn parse_lines_file<T: AsRef<Path>, F>(filename: T) -> Result<Vec<F>, HelperError>
 where
     F: FromStr,
+    F::Err: Display,
 {
-    let mut file = File::open(filename)?;
-
-    let mut content = String::new();
-    file.read_to_string(&mut content)?;
-
-    content
-        .lines()
-        .map(|l| FromStr::from_str(&l).map_err(|_| HelperError::ParsingError))
+    stream::parsed_lines(stream::open(filename)?)
+        .map(|line| {
+            line.map_err(|e: ReadError<F::Err>| match e.map(|e| e.to_string()) {
+                ReadError::Io(e) => HelperError::IoError(e),
+                e => HelperError::ParsingError(e),
+            })
+        })
         .collect()
 }
 
@@ -34,7 +48,8 @@ pub fn read_file<T: AsRef<Path>>(filename: T) -> Result<String, Box<dyn Error>>
 pub fn read_line_usize_from_file<T: AsRef<Path>>(
     filename: T,
 ) -> Result<Vec<usize>, Box<dyn Error>> {
-    let numbers: Result<Vec<_>, _> = read_file(filename)?.lines().map(|l| l.parse()).collect();
+    let numbers: Result<Vec<_>, ReadError<_>> =
+        stream::parsed_lines(stream::open(filename)?).collect();
     Ok(numbers?)
 }
 
@@ -47,100 +62,101 @@ pub fn split_once<'a>(s: &'a str, pat: &str) -> (&'a str, &'a str){
     }
 }
 
-// GRID
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Grid<T> {
-    columns: usize,
-    items: Vec<T>,
+// SOLUTIONS
+// A puzzle solution for one day. The input is parsed once, both parts are solved on the parsed
+// input. Implementors are usually unit structs named after their day.
+pub trait Solution {
+    const DAY: u8;
+
+    // the parsed puzzle input, which may borrow from the raw input
+    type Input<'a>;
+    type Error: Error + 'static;
+    type Part1: Answer;
+    type Part2: Answer;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error>;
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Part1, Self::Error>;
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Part2, Self::Error>;
 }
 
-impl<T> Grid<T> {
-    pub fn with_items(items: Vec<T>, columns: usize) -> Result<Self, GridError> {
-        let len = items.len();
-        if len % columns == 0 {
-            Ok(Grid { columns, items })
-        } else {
-            Err(GridError::UnevenRowsError(len, columns))
-        }
-    }
+// Diagnostics of how an answer was found, e.g. the number of iterations. They are not part of the
+// answer, the runner only reports them in its json output.
+pub type Details = Vec<(&'static str, String)>;
 
-    fn get_idx(&self, col: usize, row: usize) -> usize {
-        row * self.columns + col
-    }
+pub trait Answer: Display {
+    // false for parts without a puzzle, there is nothing to run or measure for them
+    const SOLVED: bool = true;
 
-    pub fn get(&self, col: usize, row: usize) -> Option<&T> {
-        // println!("{}", self.columns);
-        if col < self.columns {
-            self.items.get(self.get_idx(col, row))
-        } else {
-            None
-        }
+    fn details(&self) -> Details {
+        Details::new()
     }
+}
 
-    pub fn num_columns(&self) -> usize {
-        self.columns
-    }
+macro_rules! plain_answers {
+    ($($answer:ty),*) => {
+        $(impl Answer for $answer {})*
+    };
+}
 
-    pub fn num_rows(&self) -> usize {
-        self.items.len() / self.columns
-    }
+plain_answers!(u32, u64, u128, usize, i32, i64, i128, isize, String);
 
-    pub fn set(&mut self, col: usize, row: usize, v: T) {
-        let idx = self.get_idx(col, row);
-        self.items[idx] = v
-    }
+// An answer together with its details, displayed as the bare answer
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Detailed<T> {
+    pub answer: T,
+    pub details: Details,
+}
 
-    fn get_unchecked(&self, col: usize, row: usize) -> &T {
-        if col < self.columns {
-            &self.items[self.get_idx(col, row)]
-        } else {
-            panic!("Index out of bounds!")
+impl<T> Detailed<T> {
+    pub fn new(answer: T) -> Self {
+        Self {
+            answer,
+            details: Details::new(),
         }
     }
 
-    pub fn items(&self) -> &[T] {
-        &self.items
+    pub fn with<D: Display>(mut self, name: &'static str, detail: D) -> Self {
+        self.details.push((name, detail.to_string()));
+        self
     }
+}
 
-    pub fn items_iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
-        let rows = self.items.len() / self.columns;
-        (0..rows)
-            .cartesian_product(0..self.columns)
-            .map(move |(row, col)| ((col, row), self.get_unchecked(col, row)))
+impl<T: Display> Display for Detailed<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.answer.fmt(f)
     }
+}
 
-    pub fn iter(&self) -> impl Iterator<Item = &T> {
-        self.items.iter()
+impl<T: Display> Answer for Detailed<T> {
+    fn details(&self) -> Details {
+        self.details.clone()
     }
 }
 
-impl<T: Display> Display for Grid<T> {
+// Answer of parts without a solution, e.g. the second part of day 25 has no puzzle
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        for (i, item) in self.items.iter().enumerate() {
-            if i % self.columns == 0 {
-                writeln!(f)?;
-            }
-            write!(f, "{}", item)?;
-        }
-        Ok(())
+        write!(f, "unsolved")
     }
 }
 
+impl Answer for Unsolved {
+    const SOLVED: bool = false;
+}
+
 // ERROR HANDLING
 
 #[derive(Error, Debug)]
 pub enum HelperError {
-    #[error("could not open file")]
+    #[error("could not read file, {0}")]
     IoError(#[from] std::io::Error),
-    #[error("parsing error")]
-    ParsingError,
+    #[error("parsing error, {0}")]
+    ParsingError(ReadError<String>),
 }
 
-#[derive(Clone, Copy, Debug, Error)]
-pub enum GridError {
-    #[error("{0} items can not be divided among {1} columns")]
-    UnevenRowsError(usize, usize),
-}
 
 #[cfg(test)]
 mod tests {
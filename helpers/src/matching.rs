@@ -0,0 +1,317 @@
+use std::collections::{BTreeMap, HashSet};
+use std::hash::Hash;
+
+// Assigns every key one of its candidate values, no value is assigned twice. A key with a single
+// candidate left takes it, the value is removed from the candidates of all other keys. This repeats
+// until no key is left with a single candidate.
+pub fn eliminate<K, V>(mut candidates: BTreeMap<K, HashSet<V>>) -> Elimination<K, V>
+where
+    K: Ord + Clone,
+    V: Hash + Eq + Clone,
+{
+    let mut assigned = BTreeMap::new();
+    while let Some(key) = candidates
+        .iter()
+        .find(|(_, values)| values.len() == 1)
+        .map(|(key, _)| key.clone())
+    {
+        let values = candidates.remove(&key).unwrap_or_default();
+        if let Some(value) = values.into_iter().next() {
+            for values in candidates.values_mut() {
+                values.remove(&value);
+            }
+            assigned.insert(key, value);
+        }
+    }
+    Elimination {
+        assigned,
+        unresolved: candidates,
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Elimination<K, V> {
+    pub assigned: BTreeMap<K, V>,
+    // keys with no or with several candidates left, elimination can not decide these
+    pub unresolved: BTreeMap<K, HashSet<V>>,
+}
+
+impl<K, V> Elimination<K, V> {
+    // true if every key got a value
+    pub fn is_complete(&self) -> bool {
+        self.unresolved.is_empty()
+    }
+}
+
+// Every way to assign each key one of its candidate values without taking a value twice, the
+// perfect matchings of keys and values. They are found one at a time by backtracking, the number of
+// matchings may grow factorially with the number of keys.
+pub fn matchings<K, V>(candidates: &BTreeMap<K, HashSet<V>>) -> Matchings<K, V>
+where
+    K: Ord + Clone,
+    V: Hash + Ord + Clone,
+{
+    let mut values: Vec<V> = candidates.values().flatten().cloned().collect();
+    values.sort_unstable();
+    values.dedup();
+    // keys with few candidates first, they branch the least
+    let mut keys: Vec<(K, Vec<usize>)> = candidates
+        .iter()
+        .map(|(key, candidates)| {
+            let mut indices: Vec<usize> = candidates
+                .iter()
+                .filter_map(|v| values.binary_search(v).ok())
+                .collect();
+            indices.sort_unstable();
+            (key.clone(), indices)
+        })
+        .collect();
+    keys.sort_by_key(|(_, candidates)| candidates.len());
+    Matchings {
+        used: vec![false; values.len()],
+        values,
+        keys,
+        chosen: Vec::new(),
+        resume: Some(0),
+    }
+}
+
+// Whether the keys can be matched with their candidates in no, exactly one or several ways. The
+// search stops at the second matching, the number of all matchings may be factorial.
+pub fn solve<K, V>(candidates: &BTreeMap<K, HashSet<V>>) -> Matching<K, V>
+where
+    K: Ord + Clone,
+    V: Hash + Ord + Clone,
+{
+    let mut found: Vec<_> = matchings(candidates).take(2).collect();
+    match found.len() {
+        0 => Matching::None,
+        1 => Matching::Unique(found.remove(0)),
+        _ => Matching::Ambiguous(found),
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Matching<K, V> {
+    None,
+    Unique(BTreeMap<K, V>),
+    // the first two matchings, there may be more
+    Ambiguous(Vec<BTreeMap<K, V>>),
+}
+
+// Depth first search over the candidates of the keys in order. A candidate is only chosen if the
+// remaining keys can still be matched, so the search never runs into a dead end.
+pub struct Matchings<K, V> {
+    values: Vec<V>,
+    // keys with the indices of their candidate values
+    keys: Vec<(K, Vec<usize>)>,
+    used: Vec<bool>,
+    // position of the chosen candidate for each key of the partial matching
+    chosen: Vec<usize>,
+    // the candidate to continue with for the next key, None once all matchings are found
+    resume: Option<usize>,
+}
+
+impl<K: Clone + Ord, V: Clone> Matchings<K, V> {
+    // Undoes the last choice, returns the candidate to try instead
+    fn backtrack(&mut self) -> Option<usize> {
+        let position = self.chosen.pop()?;
+        let value = self.keys[self.chosen.len()].1[position];
+        self.used[value] = false;
+        Some(position + 1)
+    }
+
+    // Kuhn's augmenting paths, whether the keys from `first` on can get distinct unused values
+    fn can_complete(&self, first: usize) -> bool {
+        let mut owner = vec![None; self.values.len()];
+        (first..self.keys.len()).all(|key| {
+            let mut visited = vec![false; self.values.len()];
+            self.augment(key, &mut owner, &mut visited)
+        })
+    }
+
+    fn augment(&self, key: usize, owner: &mut [Option<usize>], visited: &mut [bool]) -> bool {
+        for &value in &self.keys[key].1 {
+            if self.used[value] || visited[value] {
+                continue;
+            }
+            visited[value] = true;
+            if owner[value].is_none_or(|other| self.augment(other, owner, visited)) {
+                owner[value] = Some(key);
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl<K: Clone + Ord, V: Clone> Iterator for Matchings<K, V> {
+    type Item = BTreeMap<K, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut from = self.resume.take()?;
+        loop {
+            let depth = self.chosen.len();
+            if depth == self.keys.len() {
+                let matching = self
+                    .chosen
+                    .iter()
+                    .zip(&self.keys)
+                    .map(|(&position, (key, candidates))| {
+                        (key.clone(), self.values[candidates[position]].clone())
+                    })
+                    .collect();
+                self.resume = self.backtrack();
+                return Some(matching);
+            }
+
+            let candidates = self.keys[depth].1.clone();
+            let next = (from..candidates.len()).find(|&position| {
+                let value = candidates[position];
+                if self.used[value] {
+                    return false;
+                }
+                self.used[value] = true;
+                let possible = self.can_complete(depth + 1);
+                self.used[value] = false;
+                possible
+            });
+            match next {
+                Some(position) => {
+                    self.used[candidates[position]] = true;
+                    self.chosen.push(position);
+                    from = 0;
+                }
+                None => from = self.backtrack()?,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates(keys: &[(u8, &[char])]) -> BTreeMap<u8, HashSet<char>> {
+        keys.iter()
+            .map(|(key, values)| (*key, values.iter().copied().collect()))
+            .collect()
+    }
+
+    #[test]
+    fn test_eliminate() {
+        let elimination = eliminate(candidates(&[
+            (0, &['a', 'b']),
+            (1, &['b']),
+            (2, &['a', 'c']),
+        ]));
+        assert!(elimination.is_complete());
+        let expected: BTreeMap<_, _> = vec![(0, 'a'), (1, 'b'), (2, 'c')].into_iter().collect();
+        assert_eq!(expected, elimination.assigned);
+    }
+
+    #[test]
+    fn test_matchings() {
+        let unique = candidates(&[(0, &['a', 'b']), (1, &['b']), (2, &['a', 'c'])]);
+        let expected: BTreeMap<_, _> = vec![(0, 'a'), (1, 'b'), (2, 'c')].into_iter().collect();
+        assert_eq!(Matching::Unique(expected), solve(&unique));
+
+        // 1 and 2 can swap their values
+        let ambiguous = candidates(&[(0, &['a']), (1, &['b', 'c']), (2, &['b', 'c'])]);
+        let expected: Vec<BTreeMap<_, _>> = vec![
+            vec![(0, 'a'), (1, 'b'), (2, 'c')].into_iter().collect(),
+            vec![(0, 'a'), (1, 'c'), (2, 'b')].into_iter().collect(),
+        ];
+        assert_eq!(Matching::Ambiguous(expected), solve(&ambiguous));
+        // 3 needs the value of 0
+        let mut none = ambiguous.clone();
+        none.insert(3, ['a'].iter().copied().collect());
+        assert_eq!(Matching::None, solve(&none));
+
+        // nothing to assign is a single matching
+        assert_eq!(
+            Matching::Unique(BTreeMap::new()),
+            solve(&BTreeMap::<u8, HashSet<char>>::new())
+        );
+    }
+
+    #[test]
+    fn test_matchings_prune() {
+        // 12 keys can not share 11 values, this is found without trying the 11! assignments
+        let values: Vec<char> = ('a'..='k').collect();
+        let keys: Vec<(u8, &[char])> = (0..12).map(|key| (key, &values[..])).collect();
+        assert_eq!(None, matchings(&candidates(&keys)).next());
+
+        // all 4! assignments of 4 keys to 4 values, solving only looks for the first two
+        let keys: Vec<(u8, &[char])> = (0..4).map(|key| (key, &values[..4])).collect();
+        assert_eq!(24, matchings(&candidates(&keys)).count());
+        assert!(matches!(solve(&candidates(&keys)), Matching::Ambiguous(m) if m.len() == 2));
+    }
+
+    #[test]
+    fn test_eliminate_unresolved() {
+        // 1 and 2 could swap their values, 3 has nothing left once 0 took its value
+        let elimination = eliminate(candidates(&[
+            (0, &['a']),
+            (1, &['b', 'c']),
+            (2, &['b', 'c']),
+            (3, &['a']),
+        ]));
+        assert!(!elimination.is_complete());
+        assert_eq!(Some(&'a'), elimination.assigned.get(&0));
+        assert_eq!(
+            candidates(&[(1, &['b', 'c']), (2, &['b', 'c']), (3, &[])]),
+            elimination.unresolved
+        );
+    }
+
+    #[test]
+    fn test_no_single_candidate() {
+        // a cycle, every key has two candidates. Elimination can't start, the matcher finds both
+        // ways around the cycle.
+        let cycle = candidates(&[(0, &['a', 'b']), (1, &['b', 'c']), (2, &['c', 'a'])]);
+        let elimination = eliminate(cycle.clone());
+        assert!(elimination.assigned.is_empty());
+        assert_eq!(cycle, elimination.unresolved);
+        let expected: Vec<BTreeMap<_, _>> = vec![
+            vec![(0, 'a'), (1, 'b'), (2, 'c')].into_iter().collect(),
+            vec![(0, 'b'), (1, 'c'), (2, 'a')].into_iter().collect(),
+        ];
+        assert_eq!(Matching::Ambiguous(expected), solve(&cycle));
+    }
+
+    #[test]
+    fn test_unique_matchings_are_eliminated() {
+        // A unique matching always leaves a key with a single candidate, and another one after
+        // that key is assigned. So for every candidate list of up to 4 keys and values, the matcher
+        // finds a unique matching exactly when elimination assigns every key, and never without a
+        // key with a single candidate.
+        let values = ['a', 'b', 'c', 'd'];
+        for n in 1..=4 {
+            for bits in 0..1_u32 << (n * n) {
+                let keys: Vec<(u8, Vec<char>)> = (0..n)
+                    .map(|key| {
+                        let values = (0..n)
+                            .filter(|value| bits & 1 << (key * n + value) != 0)
+                            .map(|value| values[value])
+                            .collect();
+                        (key as u8, values)
+                    })
+                    .collect();
+                let keys: Vec<(u8, &[char])> = keys.iter().map(|(k, v)| (*k, &v[..])).collect();
+                let candidates = candidates(&keys);
+
+                let single = candidates.values().any(|values| values.len() == 1);
+                let elimination = eliminate(candidates.clone());
+                match solve(&candidates) {
+                    Matching::Unique(matching) => {
+                        assert!(single, "{:?}", candidates);
+                        assert!(elimination.is_complete(), "{:?}", candidates);
+                        assert_eq!(matching, elimination.assigned);
+                    }
+                    _ => assert!(!elimination.is_complete(), "{:?}", candidates),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,385 @@
+use crate::ParseError;
+use std::num::ParseIntError;
+use std::str::FromStr;
+use thiserror::Error;
+
+// Small parser combinators for the puzzle inputs. A parser takes the remaining input and returns
+// the parsed value together with the input behind it. Values borrow from the input, nothing is
+// copied. Failures keep the part of the input where parsing failed, `parse` turns them into
+// positioned errors.
+//
+// Repetitions and alternatives backtrack over a failing parser, as long as it failed at the very
+// start of its input. Once a parser made progress, its failure is reported.
+
+pub type PResult<'a, T> = Result<(T, &'a str), Failure<'a>>;
+
+pub trait Parser<'a, T>: Fn(&'a str) -> PResult<'a, T> {}
+
+impl<'a, T, F: Fn(&'a str) -> PResult<'a, T>> Parser<'a, T> for F {}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Failure<'a> {
+    pub span: &'a str,
+    pub error: SyntaxError,
+}
+
+impl<'a> Failure<'a> {
+    pub fn new(span: &'a str, error: SyntaxError) -> Self {
+        Self { span, error }
+    }
+
+    // the failure happened behind the start of the input
+    fn made_progress(&self, input: &str) -> bool {
+        self.span.as_ptr() as usize > input.as_ptr() as usize
+    }
+}
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum SyntaxError {
+    #[error("expected {0:?}")]
+    Literal(&'static str),
+    #[error("expected {0}")]
+    Expected(&'static str),
+    #[error("invalid number, {0}")]
+    Number(ParseIntError),
+    #[error("unexpected input")]
+    Trailing,
+}
+
+// Parses the whole input, only line breaks may follow
+pub fn parse<'a, T>(
+    input: &'a str,
+    parser: impl Parser<'a, T>,
+) -> Result<T, ParseError<SyntaxError>> {
+    let (value, rest) = parser(input).map_err(|f| ParseError::at(input, f.span, f.error))?;
+    if rest.trim_end_matches('\n').is_empty() {
+        Ok(value)
+    } else {
+        Err(ParseError::at(input, rest, SyntaxError::Trailing))
+    }
+}
+
+// PRIMITIVES
+
+pub fn literal<'a>(tag: &'static str) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(tag) {
+        Some(rest) => Ok((&input[..tag.len()], rest)),
+        None => Err(Failure::new(first_char(input), SyntaxError::Literal(tag))),
+    }
+}
+
+// Decimal integer with an optional leading minus
+pub fn integer<'a, N: FromStr<Err = ParseIntError>>() -> impl Parser<'a, N> {
+    move |input: &'a str| {
+        let sign = usize::from(input.starts_with('-'));
+        let len = sign + input[sign..].bytes().take_while(u8::is_ascii_digit).count();
+        if len == sign {
+            return Err(Failure::new(
+                first_char(input),
+                SyntaxError::Expected("a number"),
+            ));
+        }
+        let (digits, rest) = input.split_at(len);
+        match digits.parse() {
+            Ok(number) => Ok((number, rest)),
+            Err(e) => Err(Failure::new(digits, SyntaxError::Number(e))),
+        }
+    }
+}
+
+pub fn any_char(input: &str) -> PResult<'_, char> {
+    match input.chars().next() {
+        Some(c) => Ok((c, &input[c.len_utf8()..])),
+        None => Err(Failure::new(input, SyntaxError::Expected("a character"))),
+    }
+}
+
+// At least one character matching the predicate
+pub fn take_while1<'a>(
+    predicate: impl Fn(char) -> bool,
+    expected: &'static str,
+) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| {
+        let len = input
+            .char_indices()
+            .find(|(_, c)| !predicate(*c))
+            .map_or(input.len(), |(i, _)| i);
+        if len == 0 {
+            Err(Failure::new(
+                first_char(input),
+                SyntaxError::Expected(expected),
+            ))
+        } else {
+            Ok(input.split_at(len))
+        }
+    }
+}
+
+// Everything in front of the tag, which must be on the same line. The tag is not consumed.
+pub fn take_until<'a>(tag: &'static str) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| {
+        let line = rest_of_line(input)?.0;
+        match line.find(tag) {
+            Some(i) => Ok(input.split_at(i)),
+            None => Err(Failure::new(line, SyntaxError::Literal(tag))),
+        }
+    }
+}
+
+// Everything up to the end of the line, the line break is not consumed
+pub fn rest_of_line(input: &str) -> PResult<'_, &str> {
+    Ok(input.split_at(input.find('\n').unwrap_or(input.len())))
+}
+
+fn first_char(input: &str) -> &str {
+    &input[..input.chars().next().map_or(0, char::len_utf8)]
+}
+
+// COMBINATORS
+
+pub fn map<'a, T, U>(parser: impl Parser<'a, T>, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+    move |input: &'a str| parser(input).map(|(value, rest)| (f(value), rest))
+}
+
+// Fails with expected, if the predicate does not hold for the parsed value
+pub fn verify<'a, T>(
+    parser: impl Parser<'a, T>,
+    predicate: impl Fn(&T) -> bool,
+    expected: &'static str,
+) -> impl Parser<'a, T> {
+    move |input: &'a str| {
+        let (value, rest) = parser(input)?;
+        if predicate(&value) {
+            Ok((value, rest))
+        } else {
+            let span = &input[..input.len() - rest.len()];
+            Err(Failure::new(span, SyntaxError::Expected(expected)))
+        }
+    }
+}
+
+// The parsed value together with the input it was parsed from
+pub fn spanned<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, (T, &'a str)> {
+    move |input: &'a str| {
+        let (value, rest) = parser(input)?;
+        Ok(((value, &input[..input.len() - rest.len()]), rest))
+    }
+}
+
+pub fn opt<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Option<T>> {
+    move |input: &'a str| match parser(input) {
+        Ok((value, rest)) => Ok((Some(value), rest)),
+        Err(f) if !f.made_progress(input) => Ok((None, input)),
+        Err(f) => Err(f),
+    }
+}
+
+// The first parser which succeeds. If both fail, the failure which got further is reported.
+pub fn alt<'a, T>(first: impl Parser<'a, T>, second: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |input: &'a str| match first(input) {
+        Err(f) if !f.made_progress(input) => {
+            second(input).map_err(|g| if g.made_progress(input) { g } else { f })
+        }
+        result => result,
+    }
+}
+
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |input: &'a str| {
+        let (a, rest) = first(input)?;
+        let (b, rest) = second(rest)?;
+        Ok(((a, b), rest))
+    }
+}
+
+pub fn preceded<'a, P, T>(
+    prefix: impl Parser<'a, P>,
+    parser: impl Parser<'a, T>,
+) -> impl Parser<'a, T> {
+    map(pair(prefix, parser), |(_, value)| value)
+}
+
+pub fn terminated<'a, T, S>(
+    parser: impl Parser<'a, T>,
+    suffix: impl Parser<'a, S>,
+) -> impl Parser<'a, T> {
+    map(pair(parser, suffix), |(value, _)| value)
+}
+
+pub fn delimited<'a, P, T, S>(
+    prefix: impl Parser<'a, P>,
+    parser: impl Parser<'a, T>,
+    suffix: impl Parser<'a, S>,
+) -> impl Parser<'a, T> {
+    preceded(prefix, terminated(parser, suffix))
+}
+
+pub fn separated_pair<'a, A, S, B>(
+    first: impl Parser<'a, A>,
+    separator: impl Parser<'a, S>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    pair(terminated(first, separator), second)
+}
+
+// `key:value`
+pub fn key_value<'a, K, V>(
+    key: impl Parser<'a, K>,
+    value: impl Parser<'a, V>,
+) -> impl Parser<'a, (K, V)> {
+    separated_pair(key, literal(":"), value)
+}
+
+// One or more items with separators in between. A separator which is not followed by an item is
+// left for the next parser.
+pub fn separated<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (first, mut rest) = item(input)?;
+        let mut items = vec![first];
+        while let Ok((_, next)) = separator(rest) {
+            match item(next) {
+                Ok((value, after)) => {
+                    items.push(value);
+                    rest = after;
+                }
+                Err(f) if !f.made_progress(next) => break,
+                Err(f) => return Err(f),
+            }
+        }
+        Ok((items, rest))
+    }
+}
+
+// One or more items right after each other, an item which consumes nothing ends the repetition
+pub fn many1<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (first, mut rest) = item(input)?;
+        let mut items = vec![first];
+        loop {
+            match item(rest) {
+                Ok((value, after)) if after.len() < rest.len() => {
+                    items.push(value);
+                    rest = after;
+                }
+                Ok(_) => break,
+                Err(f) if !f.made_progress(rest) => break,
+                Err(f) => return Err(f),
+            }
+        }
+        Ok((items, rest))
+    }
+}
+
+// One item per line, at least one. Empty input is an error, not an empty list.
+pub fn lines<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    separated(item, literal("\n"))
+}
+
+// Blocks separated by blank lines, at least one. The block parser must not accept empty lines.
+pub fn blocks<'a, T>(block: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    separated(block, literal("\n\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primitives() {
+        assert_eq!(Ok(("ab", "c")), literal("ab")("abc"));
+        assert_eq!(
+            Err(Failure::new("x", SyntaxError::Literal("ab"))),
+            literal("ab")("xbc")
+        );
+        assert_eq!(Ok((-12, " a")), integer::<i32>()("-12 a"));
+        assert!(matches!(
+            integer::<u8>()("300"),
+            Err(Failure {
+                span: "300",
+                error: SyntaxError::Number(_)
+            })
+        ));
+        assert_eq!(
+            Ok(("light red", " bags")),
+            take_until(" bag")("light red bags")
+        );
+        assert!(take_until(":")("a\nb:c").is_err());
+        assert_eq!(
+            Ok(("ab", "1")),
+            take_while1(char::is_alphabetic, "a word")("ab1")
+        );
+        assert_eq!(Ok(("ab", "\ncd")), rest_of_line("ab\ncd"));
+    }
+
+    #[test]
+    fn test_combinators() {
+        let range = separated_pair(integer::<u32>(), literal("-"), integer::<u32>());
+        let ranges = separated(map(range, |(a, b)| a..=b), literal(" or "));
+        assert_eq!(Ok((vec![1..=3, 5..=7], "")), ranges("1-3 or 5-7"));
+
+        let word = take_while1(char::is_alphanumeric, "a word");
+        assert_eq!(
+            Ok((("byr", "1937"), " x")),
+            key_value(&word, &word)("byr:1937 x")
+        );
+
+        let symbol = delimited(literal("\""), any_char, literal("\""));
+        let item = alt(symbol, map(integer::<u32>(), |n| n.to_string().remove(0)));
+        // the separator in front of | is left over
+        assert_eq!(
+            Ok((vec!['a', '4'], " | 5")),
+            separated(&item, literal(" "))("\"a\" 4 | 5")
+        );
+        assert_eq!(Ok((None, "x")), opt(&item)("x"));
+        let direction = alt(literal("e"), literal("se"));
+        assert_eq!(Ok((vec!["se", "e", "e"], "w")), many1(&direction)("seeew"));
+        assert!(many1(&direction)("w").is_err());
+
+        let positive = verify(integer::<i32>(), |n| *n > 0, "a positive number");
+        assert_eq!(
+            Err(Failure::new(
+                "-3",
+                SyntaxError::Expected("a positive number")
+            )),
+            positive("-3")
+        );
+        assert_eq!(Ok(((7, "7"), "")), spanned(&positive)("7"));
+    }
+
+    #[test]
+    fn test_parse() {
+        let numbers = |input| parse(input, blocks(lines(integer::<u32>())));
+        assert_eq!(Ok(vec![vec![1, 2], vec![3]]), numbers("1\n2\n\n3\n"));
+        // there has to be at least one line
+        for empty in &["", "\n"] {
+            let error = parse(empty, lines(integer::<u32>())).unwrap_err();
+            assert_eq!(
+                (1, 1, SyntaxError::Expected("a number")),
+                (error.line, error.column, error.error)
+            );
+        }
+
+        // failures after progress are reported at their position
+        let error = numbers("1\n2\n\n3x").unwrap_err();
+        assert_eq!(
+            (4, 2, SyntaxError::Trailing),
+            (error.line, error.column, error.error)
+        );
+        let error = parse(
+            "1-x",
+            separated_pair(integer::<u32>(), literal("-"), integer::<u32>()),
+        )
+        .unwrap_err();
+        assert_eq!((1, 3), (error.line, error.column));
+        assert_eq!(
+            "line 1, column 3: expected a number\n  |\n1 | 1-x\n  |   ^",
+            error.to_string()
+        );
+    }
+}
@@ -0,0 +1,254 @@
+use std::collections::HashMap;
+
+// A position in N dimensional space
+pub type Point<const N: usize> = [isize; N];
+
+// An unbounded grid in N dimensions. Only cells differing from the default value are stored, all
+// other cells have the default value.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SparseGrid<T, const N: usize> {
+    cells: HashMap<Point<N>, T>,
+    default: T,
+    bounds: Option<BoundingBox<N>>,
+}
+
+impl<T: PartialEq, const N: usize> SparseGrid<T, N> {
+    pub fn new(default: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    pub fn get(&self, point: &Point<N>) -> &T {
+        self.cells.get(point).unwrap_or(&self.default)
+    }
+
+    pub fn set(&mut self, point: Point<N>, value: T) {
+        if value == self.default {
+            let removed = self.cells.remove(&point).is_some();
+            // the bounds only shrink if the cell was on the border
+            if removed && self.bounds.is_some_and(|b| b.is_on_border(&point)) {
+                self.bounds = BoundingBox::around(self.cells.keys());
+            }
+        } else {
+            self.cells.insert(point, value);
+            match &mut self.bounds {
+                Some(bounds) => bounds.include(&point),
+                None => self.bounds = Some(BoundingBox::new(point, point)),
+            }
+        }
+    }
+
+    // Resets every cell to the default value, keeping the allocated memory
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.bounds = None;
+    }
+
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    // Number of cells with another than the default value
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    // Cells with another than the default value, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (&Point<N>, &T)> {
+        self.cells.iter()
+    }
+
+    // Smallest box containing all cells with another than the default value, None if there are
+    // none
+    pub fn bounds(&self) -> Option<BoundingBox<N>> {
+        self.bounds
+    }
+
+    // The 3^N - 1 cells differing by at most 1 in every coordinate
+    pub fn neighbours(&self, point: Point<N>) -> impl Iterator<Item = (Point<N>, &T)> + '_ {
+        neighbour_offsets::<N>().map(move |offset| {
+            let neighbour = add(point, offset);
+            (neighbour, self.get(&neighbour))
+        })
+    }
+}
+
+// Offsets to the 3^N - 1 neighbours of a point
+pub fn neighbour_offsets<const N: usize>() -> impl Iterator<Item = Point<N>> {
+    BoundingBox::new([-1; N], [1; N])
+        .points()
+        .filter(|offset| offset.iter().any(|&d| d != 0))
+}
+
+pub(crate) fn add<const N: usize>(mut point: Point<N>, offset: Point<N>) -> Point<N> {
+    point
+        .iter_mut()
+        .zip(offset.iter())
+        .for_each(|(p, d)| *p += d);
+    point
+}
+
+// An axis aligned box, min and max are inclusive
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BoundingBox<const N: usize> {
+    pub min: Point<N>,
+    pub max: Point<N>,
+}
+
+impl<const N: usize> BoundingBox<N> {
+    pub fn new(min: Point<N>, max: Point<N>) -> Self {
+        Self { min, max }
+    }
+
+    fn around<'a, I: IntoIterator<Item = &'a Point<N>>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = *points.next()?;
+        let mut bounds = Self::new(first, first);
+        points.for_each(|p| bounds.include(p));
+        Some(bounds)
+    }
+
+    // Extends the box, so it contains the point
+    pub fn include(&mut self, point: &Point<N>) {
+        for (i, &c) in point.iter().enumerate() {
+            self.min[i] = self.min[i].min(c);
+            self.max[i] = self.max[i].max(c);
+        }
+    }
+
+    pub fn contains(&self, point: &Point<N>) -> bool {
+        (0..N).all(|i| self.min[i] <= point[i] && point[i] <= self.max[i])
+    }
+
+    fn is_on_border(&self, point: &Point<N>) -> bool {
+        (0..N).any(|i| self.min[i] == point[i] || point[i] == self.max[i])
+    }
+
+    // The box extended by amount in every direction
+    pub fn grow(&self, amount: isize) -> Self {
+        Self::new(self.min.map(|c| c - amount), self.max.map(|c| c + amount))
+    }
+
+    // All points inside of the box, the first coordinate changes fastest
+    pub fn points(&self) -> Points<N> {
+        let next = if (0..N).all(|i| self.min[i] <= self.max[i]) {
+            Some(self.min)
+        } else {
+            None
+        };
+        Points {
+            bounds: *self,
+            next,
+        }
+    }
+}
+
+// Iterator over the points of a bounding box
+#[derive(Clone, Debug)]
+pub struct Points<const N: usize> {
+    bounds: BoundingBox<N>,
+    next: Option<Point<N>>,
+}
+
+impl<const N: usize> Iterator for Points<N> {
+    type Item = Point<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        let BoundingBox { min, max } = self.bounds;
+        // count up like an odometer, wrapping to min when a coordinate passes max
+        let mut point = current;
+        self.next = None;
+        for i in 0..N {
+            if point[i] < max[i] {
+                point[i] += 1;
+                self.next = Some(point);
+                break;
+            }
+            point[i] = min[i];
+        }
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_set_default() {
+        let mut grid = SparseGrid::new('.');
+        assert_eq!(&'.', grid.get(&[5, -3]));
+        grid.set([5, -3], '#');
+        assert_eq!(&'#', grid.get(&[5, -3]));
+        assert_eq!(1, grid.len());
+
+        // default values are not stored
+        grid.set([5, -3], '.');
+        grid.set([1, 1], '.');
+        assert!(grid.is_empty());
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid::new(false);
+        assert_eq!(None, grid.bounds());
+
+        grid.set([0, 0, 0], true);
+        grid.set([2, -1, 0], true);
+        grid.set([1, 3, -4], true);
+        assert_eq!(
+            Some(BoundingBox::new([0, -1, -4], [2, 3, 0])),
+            grid.bounds()
+        );
+
+        // inner cells don't change the bounds, border cells do
+        grid.set([1, 0, 0], true);
+        grid.set([1, 0, 0], false);
+        assert_eq!(
+            Some(BoundingBox::new([0, -1, -4], [2, 3, 0])),
+            grid.bounds()
+        );
+        grid.set([1, 3, -4], false);
+        assert_eq!(Some(BoundingBox::new([0, -1, 0], [2, 0, 0])), grid.bounds());
+
+        grid.set([0, 0, 0], false);
+        grid.set([2, -1, 0], false);
+        assert_eq!(None, grid.bounds());
+    }
+
+    #[test]
+    fn test_points() {
+        let points: Vec<_> = BoundingBox::new([0, 5], [1, 7]).points().collect();
+        assert_eq!(vec![[0, 5], [1, 5], [0, 6], [1, 6], [0, 7], [1, 7]], points);
+        assert_eq!(1, BoundingBox::new([3], [3]).points().count());
+        assert_eq!(0, BoundingBox::new([3, 1], [2, 1]).points().count());
+        assert_eq!(
+            5 * 5,
+            BoundingBox::new([0, 0], [2, 2]).grow(1).points().count()
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        assert_eq!(2, neighbour_offsets::<1>().count());
+        assert_eq!(8, neighbour_offsets::<2>().count());
+        assert_eq!(26, neighbour_offsets::<3>().count());
+        assert_eq!(80, neighbour_offsets::<4>().count());
+
+        let mut grid = SparseGrid::new(0);
+        grid.set([0, 0], 1);
+        grid.set([1, 1], 2);
+        grid.set([3, 3], 3);
+        let sum: i32 = grid.neighbours([0, 1]).map(|(_, v)| v).sum();
+        assert_eq!(3, sum);
+        assert!(grid.neighbours([0, 1]).all(|(p, _)| p != [0, 1]));
+    }
+}
@@ -0,0 +1,232 @@
+use crate::ParseError;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::marker::PhantomData;
+use std::path::Path;
+use std::str::FromStr;
+
+// Lazy readers over any BufRead, e.g. a file, stdin or a byte slice. Only the current line, record
+// or value is kept in memory, so inputs don't need to fit into memory as a whole.
+
+pub fn open<P: AsRef<Path>>(path: P) -> io::Result<BufReader<File>> {
+    Ok(BufReader::new(File::open(path)?))
+}
+
+// Every line parsed with FromStr, errors span the whole line
+pub fn parsed_lines<R: BufRead, T: FromStr>(reader: R) -> ParsedLines<R, T> {
+    ParsedLines {
+        lines: reader.lines(),
+        line: 0,
+        parsed: PhantomData,
+    }
+}
+
+// Records separated by one or more blank lines, the lines of a record are joined with '\n'
+pub fn records<R: BufRead>(reader: R) -> Records<R> {
+    Records {
+        lines: reader.lines(),
+    }
+}
+
+// Comma separated values, surrounding whitespace and line breaks are ignored
+pub fn comma_separated<R: BufRead, T: FromStr>(reader: R) -> CommaSeparated<R, T> {
+    CommaSeparated {
+        reader,
+        buffer: Vec::new(),
+        position: 0,
+        parsed: PhantomData,
+    }
+}
+
+pub struct ParsedLines<R, T> {
+    lines: io::Lines<R>,
+    // number of the last line read
+    line: usize,
+    parsed: PhantomData<fn() -> T>,
+}
+
+impl<R: BufRead, T: FromStr> Iterator for ParsedLines<R, T> {
+    type Item = Result<T, ReadError<T::Err>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.lines.next()?;
+        self.line += 1;
+        Some(line.map_err(ReadError::Io).and_then(|line| {
+            T::from_str(&line).map_err(|e| ReadError::Parse(ParseError::line(self.line, &line, e)))
+        }))
+    }
+}
+
+pub struct Records<R> {
+    lines: io::Lines<R>,
+}
+
+impl<R: BufRead> Iterator for Records<R> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut record: Option<String> = None;
+        for line in &mut self.lines {
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e)),
+            };
+            match (&mut record, line.is_empty()) {
+                // blank lines in front of a record are skipped
+                (None, true) => {}
+                (Some(_), true) => break,
+                (None, false) => record = Some(line),
+                (Some(record), false) => {
+                    record.push('\n');
+                    record.push_str(&line);
+                }
+            }
+        }
+        record.map(Ok)
+    }
+}
+
+pub struct CommaSeparated<R, T> {
+    reader: R,
+    buffer: Vec<u8>,
+    // number of the last value read
+    position: usize,
+    parsed: PhantomData<fn() -> T>,
+}
+
+impl<R: BufRead, T: FromStr> Iterator for CommaSeparated<R, T> {
+    type Item = Result<T, ReadError<T::Err>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.buffer.clear();
+        match self.reader.read_until(b',', &mut self.buffer) {
+            Ok(0) => return None,
+            Ok(_) => {}
+            Err(e) => return Some(Err(ReadError::Io(e))),
+        }
+        let value = match std::str::from_utf8(&self.buffer) {
+            Ok(value) => value.trim_end_matches(',').trim(),
+            Err(e) => return Some(Err(io::Error::new(io::ErrorKind::InvalidData, e).into())),
+        };
+        // a line break after the last value is no value of its own
+        if value.is_empty() && !self.buffer.ends_with(b",") {
+            return None;
+        }
+        self.position += 1;
+        Some(T::from_str(value).map_err(|error| ReadError::Value {
+            position: self.position,
+            value: value.to_string(),
+            error,
+        }))
+    }
+}
+
+#[derive(Debug)]
+pub enum ReadError<E> {
+    Io(io::Error),
+    Parse(ParseError<E>),
+    // values are counted from 1
+    Value {
+        position: usize,
+        value: String,
+        error: E,
+    },
+}
+
+impl<E> ReadError<E> {
+    pub fn map<F, M: FnOnce(E) -> F>(self, f: M) -> ReadError<F> {
+        match self {
+            ReadError::Io(e) => ReadError::Io(e),
+            ReadError::Parse(e) => ReadError::Parse(e.map(f)),
+            ReadError::Value {
+                position,
+                value,
+                error,
+            } => ReadError::Value {
+                position,
+                value,
+                error: f(error),
+            },
+        }
+    }
+}
+
+impl<E> From<io::Error> for ReadError<E> {
+    fn from(e: io::Error) -> Self {
+        ReadError::Io(e)
+    }
+}
+
+// implemented by hand, because the error is generic
+impl<E: Display> Display for ReadError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "could not read input, {}", e),
+            ReadError::Parse(e) => write!(f, "{}", e),
+            ReadError::Value {
+                position,
+                value,
+                error,
+            } => write!(f, "value {} `{}`: {}", position, value, error),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ReadError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReadError::Io(e) => Some(e),
+            ReadError::Parse(e) => Some(e),
+            ReadError::Value { error, .. } => Some(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::num::ParseIntError;
+
+    #[test]
+    fn test_parsed_lines() {
+        let numbers: Result<Vec<u32>, _> = parsed_lines("1\n2\n3\n".as_bytes()).collect();
+        assert_eq!(vec![1, 2, 3], numbers.unwrap());
+
+        // parsing is lazy, an error only ends its own line, the lines behind it are still read
+        let mut numbers = parsed_lines::<_, u32>("1\nx\n3".as_bytes());
+        assert_eq!(1, numbers.next().unwrap().unwrap());
+        match numbers.next() {
+            Some(Err(ReadError::Parse(e))) => assert_eq!((2, "x"), (e.line, e.snippet.as_str())),
+            _ => panic!("expected a parse error"),
+        }
+        assert_eq!(3, numbers.next().unwrap().unwrap());
+    }
+
+    #[test]
+    fn test_records() {
+        let input = "\na b\nc\n\n\nd\n\ne f\n";
+        let records: Vec<_> = records(input.as_bytes()).map(Result::unwrap).collect();
+        assert_eq!(vec!["a b\nc", "d", "e f"], records);
+        assert_eq!(0, super::records("\n\n".as_bytes()).count());
+    }
+
+    #[test]
+    fn test_comma_separated() {
+        let values: Result<Vec<u32>, _> = comma_separated("0,3, 6\n,12\n".as_bytes()).collect();
+        assert_eq!(vec![0, 3, 6, 12], values.unwrap());
+
+        let values: Vec<Result<u32, _>> = comma_separated("1,,2".as_bytes()).collect();
+        assert_eq!(3, values.len());
+        assert!(matches!(
+            &values[1],
+            Err(ReadError::Value { position: 2, value, .. }) if value.is_empty()
+        ));
+        let error: &ReadError<ParseIntError> = values[1].as_ref().unwrap_err();
+        assert_eq!(
+            "value 2 ``: cannot parse integer from empty string",
+            error.to_string()
+        );
+    }
+}